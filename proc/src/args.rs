@@ -0,0 +1,478 @@
+//! Parsing of the arguments passed to `#[assert(...)]`.
+
+use crate::trait_expr::TraitExpr;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{
+    bracketed, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token, Error, Expr, GenericArgument, Generics, Ident, Result, Token, Type,
+};
+
+/// The comma-separated predicates of an `#[assert(...)]` attribute.
+pub struct Args {
+    pub predicates: Punctuated<Predicate, Token![,]>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Args {
+            predicates: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// The comma-separated predicates of an `#[assert(...)]` attribute on a field
+/// of the annotated item.
+pub struct FieldArgs {
+    pub predicates: Punctuated<LayoutPredicate, Token![,]>,
+}
+
+impl Parse for FieldArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(FieldArgs {
+            predicates: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// A single assertion over the annotated item.
+pub enum Predicate {
+    /// `size <cmp> <expr>` or `align <cmp> <expr>`.
+    Layout(LayoutPredicate),
+    /// `impl(<trait_expr>)`.
+    Impl(ImplPredicate),
+    /// `for <params> in [<args>, ...]: <predicate>`.
+    Instances(InstancesPredicate),
+    /// `for<<generics>> Self: <trait_expr>`.
+    Quantified(QuantifiedPredicate),
+    /// `transparent` or `transparent(<type>)`.
+    Transparent(TransparentPredicate),
+    /// `no_padding`.
+    NoPadding(Ident),
+    /// `naturally_aligned`.
+    NaturallyAligned(Ident),
+    /// `max_variant_size = <expr>`.
+    MaxVariantSize(MaxVariantSizePredicate),
+}
+
+impl Parse for Predicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![impl]) {
+            input.parse().map(Predicate::Impl)
+        } else if input.peek(Token![for]) && input.peek2(Token![<]) {
+            input.parse().map(Predicate::Quantified)
+        } else if input.peek(Token![for]) {
+            input.parse().map(Predicate::Instances)
+        } else {
+            match input.fork().parse::<Ident>() {
+                Ok(ident) if ident == "transparent" => {
+                    input.parse().map(Predicate::Transparent)
+                }
+                Ok(ident) if ident == "no_padding" => {
+                    input.parse().map(Predicate::NoPadding)
+                }
+                Ok(ident) if ident == "naturally_aligned" => {
+                    input.parse().map(Predicate::NaturallyAligned)
+                }
+                Ok(ident) if ident == "max_variant_size" => {
+                    input.parse().map(Predicate::MaxVariantSize)
+                }
+                Ok(ident) if Property::kind(&ident).is_some() => {
+                    input.parse().map(Predicate::Layout)
+                }
+                _ => Err(input.error(
+                    "expected `size`, `align`, `impl`, `transparent`, \
+                     `no_padding`, `naturally_aligned`, `max_variant_size`, \
+                     or `for`",
+                )),
+            }
+        }
+    }
+}
+
+impl ToTokens for Predicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Predicate::Layout(predicate) => predicate.to_tokens(tokens),
+            Predicate::Impl(predicate) => predicate.to_tokens(tokens),
+            Predicate::Instances(predicate) => predicate.to_tokens(tokens),
+            Predicate::Quantified(predicate) => predicate.to_tokens(tokens),
+            Predicate::Transparent(predicate) => predicate.to_tokens(tokens),
+            Predicate::NoPadding(ident) => ident.to_tokens(tokens),
+            Predicate::NaturallyAligned(ident) => ident.to_tokens(tokens),
+            Predicate::MaxVariantSize(predicate) => predicate.to_tokens(tokens),
+        }
+    }
+}
+
+/// A predicate checked for each listed instantiation of a generic item.
+pub struct InstancesPredicate {
+    pub for_token: Token![for],
+    pub params: Vec<Ident>,
+    pub in_token: Token![in],
+    pub bracket_token: token::Bracket,
+    pub instances: Punctuated<Instance, Token![,]>,
+    pub colon_token: Token![:],
+    pub predicate: Box<Predicate>,
+}
+
+impl Parse for InstancesPredicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let for_token = input.parse()?;
+
+        // Multiple parameters are grouped, as are their arguments.
+        let grouped = input.peek(token::Paren);
+        let params = if grouped {
+            let content;
+            parenthesized!(content in input);
+            let params =
+                Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            params.into_iter().collect()
+        } else {
+            vec![input.parse()?]
+        };
+
+        let in_token = input.parse()?;
+        let content;
+        let bracket_token = bracketed!(content in input);
+        let parser = if grouped {
+            Instance::parse_grouped
+        } else {
+            Instance::parse_single
+        };
+        let instances = Punctuated::parse_terminated_with(&content, parser)?;
+        for instance in &instances {
+            if instance.args.len() != params.len() {
+                return Err(Error::new(
+                    instance.span,
+                    format!(
+                        "expected {} generic argument(s), found {}",
+                        params.len(),
+                        instance.args.len(),
+                    ),
+                ));
+            }
+        }
+
+        let colon_token = input.parse()?;
+        let predicate: Predicate = input.parse()?;
+        if let Predicate::Instances(_) | Predicate::Quantified(_) = predicate {
+            return Err(Error::new_spanned(
+                predicate,
+                "`for` predicates cannot be nested",
+            ));
+        }
+
+        Ok(InstancesPredicate {
+            for_token,
+            params,
+            in_token,
+            bracket_token,
+            instances,
+            colon_token,
+            predicate: Box::new(predicate),
+        })
+    }
+}
+
+impl ToTokens for InstancesPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.for_token.to_tokens(tokens);
+        match self.params.as_slice() {
+            [param] => param.to_tokens(tokens),
+            params => token::Paren::default().surround(tokens, |tokens| {
+                for param in params {
+                    param.to_tokens(tokens);
+                    Token![,](param.span()).to_tokens(tokens);
+                }
+            }),
+        }
+        self.in_token.to_tokens(tokens);
+        self.bracket_token.surround(tokens, |tokens| {
+            self.instances.to_tokens(tokens);
+        });
+        self.colon_token.to_tokens(tokens);
+        self.predicate.to_tokens(tokens);
+    }
+}
+
+/// The generic arguments of a single instantiation.
+pub struct Instance {
+    pub span: Span,
+    pub args: Vec<GenericArgument>,
+}
+
+impl Instance {
+    /// Parses the argument of a single parameter, as in `for T in [u8]`.
+    fn parse_single(input: ParseStream) -> Result<Self> {
+        Ok(Instance {
+            span: input.span(),
+            args: vec![input.parse()?],
+        })
+    }
+
+    /// Parses parenthesized arguments, as in `for (K, V) in [(u8, u16)]`.
+    fn parse_grouped(input: ParseStream) -> Result<Self> {
+        let span = input.span();
+        let content;
+        parenthesized!(content in input);
+        let args = Punctuated::<GenericArgument, Token![,]>::parse_terminated(
+            &content,
+        )?;
+        Ok(Instance {
+            span,
+            args: args.into_iter().collect(),
+        })
+    }
+}
+
+impl ToTokens for Instance {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self.args.as_slice() {
+            [arg] => arg.to_tokens(tokens),
+            args => token::Paren::default().surround(tokens, |tokens| {
+                for arg in args {
+                    arg.to_tokens(tokens);
+                    Token![,](self.span).to_tokens(tokens);
+                }
+            }),
+        }
+    }
+}
+
+/// A trait expression that must hold for all generic parameters satisfying
+/// the given bounds.
+pub struct QuantifiedPredicate {
+    pub for_token: Token![for],
+    pub generics: Generics,
+    pub self_token: Token![Self],
+    pub colon_token: Token![:],
+    pub expr: TraitExpr,
+}
+
+impl Parse for QuantifiedPredicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(QuantifiedPredicate {
+            for_token: input.parse()?,
+            generics: input.parse()?,
+            self_token: input.parse()?,
+            colon_token: input.parse()?,
+            expr: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for QuantifiedPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.for_token.to_tokens(tokens);
+        self.generics.to_tokens(tokens);
+        self.self_token.to_tokens(tokens);
+        self.colon_token.to_tokens(tokens);
+        self.expr.to_tokens(tokens);
+    }
+}
+
+/// A logical expression of traits that the item must satisfy.
+pub struct ImplPredicate {
+    pub impl_token: Token![impl],
+    pub paren_token: token::Paren,
+    pub expr: TraitExpr,
+}
+
+impl Parse for ImplPredicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(ImplPredicate {
+            impl_token: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            expr: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for ImplPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.impl_token.to_tokens(tokens);
+        self.paren_token.surround(tokens, |tokens| {
+            self.expr.to_tokens(tokens);
+        });
+    }
+}
+
+/// Requires the item to be `#[repr(transparent)]`, optionally with the same
+/// size and alignment as the given type.
+pub struct TransparentPredicate {
+    pub ident: Ident,
+    pub inner: Option<(token::Paren, Type)>,
+}
+
+impl Parse for TransparentPredicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse()?;
+        let inner = if input.peek(token::Paren) {
+            let content;
+            let paren_token = parenthesized!(content in input);
+            Some((paren_token, content.parse()?))
+        } else {
+            None
+        };
+        Ok(TransparentPredicate { ident, inner })
+    }
+}
+
+impl ToTokens for TransparentPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+        if let Some((paren_token, ty)) = &self.inner {
+            paren_token.surround(tokens, |tokens| ty.to_tokens(tokens));
+        }
+    }
+}
+
+/// An upper bound on the size of the fields of each variant of an enum.
+pub struct MaxVariantSizePredicate {
+    pub ident: Ident,
+    pub eq_token: Token![=],
+    pub value: Expr,
+}
+
+impl Parse for MaxVariantSizePredicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MaxVariantSizePredicate {
+            ident: input.parse()?,
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for MaxVariantSizePredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+        self.eq_token.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+/// A comparison of the item's size or alignment against a `usize` constant.
+pub struct LayoutPredicate {
+    pub property: Property,
+    pub cmp: Cmp,
+    pub value: Expr,
+}
+
+impl Parse for LayoutPredicate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(LayoutPredicate {
+            property: input.parse()?,
+            cmp: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for LayoutPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.property.ident.to_tokens(tokens);
+        self.cmp.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+/// The measured property of a type.
+pub struct Property {
+    pub ident: Ident,
+    pub kind: PropertyKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PropertyKind {
+    Size,
+    Align,
+    /// The byte offset of a field, which only applies to field predicates.
+    Offset,
+}
+
+impl Property {
+    fn kind(ident: &Ident) -> Option<PropertyKind> {
+        if ident == "size" {
+            Some(PropertyKind::Size)
+        } else if ident == "align" {
+            Some(PropertyKind::Align)
+        } else if ident == "offset" {
+            Some(PropertyKind::Offset)
+        } else {
+            None
+        }
+    }
+
+    /// The `core::mem` function or macro that measures this property.
+    pub fn mem_fn(&self) -> Ident {
+        let name = match self.kind {
+            PropertyKind::Size => "size_of",
+            PropertyKind::Align => "align_of",
+            PropertyKind::Offset => "offset_of",
+        };
+        Ident::new(name, self.ident.span())
+    }
+}
+
+impl Parse for Property {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        match Property::kind(&ident) {
+            Some(kind) => Ok(Property { ident, kind }),
+            None => Err(Error::new(
+                ident.span(),
+                "expected `size`, `align`, or `offset`",
+            )),
+        }
+    }
+}
+
+/// A comparison operator.
+pub enum Cmp {
+    Eq(Token![==]),
+    Ne(Token![!=]),
+    Lt(Token![<]),
+    Le(Token![<=]),
+    Gt(Token![>]),
+    Ge(Token![>=]),
+}
+
+impl Parse for Cmp {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // Two-character operators must be checked before their prefixes.
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![==]) {
+            input.parse().map(Cmp::Eq)
+        } else if lookahead.peek(Token![!=]) {
+            input.parse().map(Cmp::Ne)
+        } else if lookahead.peek(Token![<=]) {
+            input.parse().map(Cmp::Le)
+        } else if lookahead.peek(Token![>=]) {
+            input.parse().map(Cmp::Ge)
+        } else if lookahead.peek(Token![<]) {
+            input.parse().map(Cmp::Lt)
+        } else if lookahead.peek(Token![>]) {
+            input.parse().map(Cmp::Gt)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for Cmp {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Cmp::Eq(t) => t.to_tokens(tokens),
+            Cmp::Ne(t) => t.to_tokens(tokens),
+            Cmp::Lt(t) => t.to_tokens(tokens),
+            Cmp::Le(t) => t.to_tokens(tokens),
+            Cmp::Gt(t) => t.to_tokens(tokens),
+            Cmp::Ge(t) => t.to_tokens(tokens),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Derives for the bit validity traits of `static_assertions_next`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_quote, spanned::Spanned, Data, DeriveInput, Error, Path, Result,
+};
+
+/// A marker trait that requires every field to implement it.
+#[derive(Clone, Copy)]
+pub enum BitTrait {
+    Zeroable,
+    AnyBitPattern,
+}
+
+impl BitTrait {
+    fn name(self) -> &'static str {
+        match self {
+            BitTrait::Zeroable => "Zeroable",
+            BitTrait::AnyBitPattern => "AnyBitPattern",
+        }
+    }
+
+    /// Describes values of types that implement the trait.
+    fn validity(self) -> &'static str {
+        match self {
+            BitTrait::Zeroable => "valid when zeroed",
+            BitTrait::AnyBitPattern => "valid for any bit pattern",
+        }
+    }
+
+    fn path(self, krate: &Path) -> TokenStream {
+        let ident = format_ident!("{}", self.name());
+        quote!(#krate::#ident)
+    }
+}
+
+/// Returns the path given by `#[bits(crate = <path>)]`, which defaults to
+/// `::static_assertions_next`.
+fn crate_path(item: &DeriveInput) -> Result<Path> {
+    let mut krate = parse_quote!(::static_assertions_next);
+    for attr in &item.attrs {
+        if !attr.path().is_ident("bits") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate = <path>`"))
+            }
+        })?;
+    }
+    Ok(krate)
+}
+
+/// Implements `bit_trait` for `item` after checking that each of its fields
+/// implements it, so that errors name the offending field.
+pub fn expand(item: &DeriveInput, bit_trait: BitTrait) -> Result<TokenStream> {
+    let fields: Vec<&syn::Field> = match &item.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Union(data) => data.fields.named.iter().collect(),
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span,
+                format!(
+                    "`{}` cannot be derived for enums, since only some \
+                     discriminants are valid",
+                    bit_trait.name(),
+                ),
+            ));
+        }
+    };
+
+    let path = bit_trait.path(&crate_path(item)?);
+    let mut generics = item.generics.clone();
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #path));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut checks = TokenStream::new();
+    for (index, field) in fields.iter().enumerate() {
+        let name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => index.to_string(),
+        };
+        let message = format!(
+            "field `{}` of `{}` is not known to be {}",
+            name,
+            item.ident,
+            bit_trait.validity(),
+        );
+        let label = format!("`{{Self}}` is not `{}`", bit_trait.name());
+        let field_trait = format_ident!("Field{}", index);
+        let ty = &field.ty;
+        // Each field is checked in its own scope.
+        checks.extend(quote_spanned! {ty.span()=> {
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            trait #field_trait {}
+            impl<T: ?Sized + #path> #field_trait for T {}
+            fn assert_field<T: ?Sized + #field_trait>() {}
+            assert_field::<#ty>();
+        }});
+    }
+
+    let ident = &item.ident;
+
+    Ok(quote! {
+        const _: () = {
+            // Taking the item brings its implied bounds, such as `T: 'a`.
+            #[allow(dead_code)]
+            fn assert_fields #impl_generics (_: &#ident #ty_generics)
+                #where_clause
+            {
+                #checks
+            }
+            unsafe impl #impl_generics #path for #ident #ty_generics
+                #where_clause {}
+        };
+    })
+}
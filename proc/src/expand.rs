@@ -0,0 +1,805 @@
+//! Code generation for `#[assert(...)]`.
+
+use crate::{
+    args::{
+        Args, FieldArgs, ImplPredicate, Instance, InstancesPredicate,
+        LayoutPredicate, MaxVariantSizePredicate, Predicate, PropertyKind,
+        QuantifiedPredicate, TransparentPredicate,
+    },
+    trait_expr,
+};
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use std::mem;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Error,
+    GenericParam, Ident, Index, Member, Meta, Result, Token, Type,
+};
+
+/// Generates a `const _` check for every predicate over `item` and its fields.
+///
+/// The `#[assert(...)]` attributes of fields are removed from `item`, since
+/// they aren't valid outside of this macro.
+pub fn expand(args: &Args, item: &mut DeriveInput) -> Result<TokenStream> {
+    let fields = take_field_args(item);
+    let mut checks = Checks {
+        lifetimes: item_lifetimes(item),
+        fields: struct_fields(item),
+        variants: enum_variants(item),
+        ..Checks::default()
+    };
+    for field in fields? {
+        expand_field(item, &field)?.to_tokens(&mut checks.consts);
+    }
+    for predicate in &args.predicates {
+        match predicate {
+            Predicate::Instances(instances) => {
+                if let Predicate::Transparent(transparent) =
+                    &*instances.predicate
+                {
+                    check_repr_transparent(item, transparent)?;
+                }
+                for instance in &instances.instances {
+                    let ty = instance_ty(item, instances, instance)?;
+                    checks.push(
+                        &instances.predicate,
+                        &ty,
+                        Some((&instances.params, instance)),
+                    )?;
+                }
+            }
+            Predicate::Quantified(quantified) => {
+                let check = expand_quantified(item, quantified)?;
+                checks.impls.extend(check);
+            }
+            Predicate::Transparent(transparent) => {
+                check_repr_transparent(item, transparent)?;
+                // Without a type to compare against, the item needn't be
+                // concrete.
+                if transparent.inner.is_some() {
+                    let ty = self_ty(item, predicate)?;
+                    checks.push(predicate, &ty, None)?;
+                }
+            }
+            _ => {
+                let ty = self_ty(item, predicate)?;
+                checks.push(predicate, &ty, None)?;
+            }
+        }
+    }
+    Ok(checks.finish())
+}
+
+/// The checks generated for an item.
+#[derive(Default)]
+struct Checks {
+    consts: TokenStream,
+    impls: TokenStream,
+    /// The lifetime parameters of the item, which are replaced by `'static`
+    /// in types given by predicates.
+    lifetimes: Vec<Ident>,
+    /// The fields of the item and their types, if it's a struct.
+    fields: Option<Vec<(Member, TokenStream)>>,
+    /// The variants of the item and their field types, if it's an enum.
+    variants: Option<Vec<(Ident, Vec<TokenStream>)>>,
+}
+
+impl Checks {
+    /// Adds the checks of a non-`for` predicate over `ty`, which may be an
+    /// instance of the item's `params`, in which case errors point at it.
+    fn push(
+        &mut self,
+        predicate: &Predicate,
+        ty: &TokenStream,
+        instance: Option<(&[Ident], &Instance)>,
+    ) -> Result<()> {
+        let span = instance.map(|(_, instance)| instance.span);
+        match predicate {
+            Predicate::Layout(layout) => {
+                let property = &layout.property;
+                if property.kind == PropertyKind::Offset {
+                    return Err(Error::new(
+                        property.ident.span(),
+                        "`offset` can only be asserted on fields",
+                    ));
+                }
+                let span = span.unwrap_or_else(|| property.ident.span());
+                let mem_fn = property.mem_fn();
+                let actual =
+                    quote_spanned!(span=> ::core::mem::#mem_fn::<#ty>());
+                expand_layout(layout, &ty.to_string(), actual, span)
+                    .to_tokens(&mut self.consts);
+            }
+            Predicate::Impl(imp) => {
+                let span = span.unwrap_or(imp.impl_token.span);
+                expand_impl(imp, ty, span).to_tokens(&mut self.impls);
+            }
+            Predicate::Transparent(transparent) => {
+                if let Some((_, inner)) = &transparent.inner {
+                    let span = span.unwrap_or_else(|| transparent.ident.span());
+                    let inner =
+                        self.concrete(inner.to_token_stream(), ty, instance);
+                    expand_transparent(ty, &inner, span)
+                        .to_tokens(&mut self.consts);
+                }
+            }
+            Predicate::NoPadding(ident) => {
+                let fields = self.struct_fields(ident, ty, instance)?;
+                let span = span.unwrap_or_else(|| ident.span());
+                expand_no_padding(ty, &fields, span)
+                    .to_tokens(&mut self.consts);
+            }
+            Predicate::NaturallyAligned(ident) => {
+                let fields = self.struct_fields(ident, ty, instance)?;
+                let span = span.unwrap_or_else(|| ident.span());
+                expand_naturally_aligned(ty, &fields, span)
+                    .to_tokens(&mut self.consts);
+            }
+            Predicate::MaxVariantSize(max) => {
+                let ident = &max.ident;
+                let variants = self.variants.as_ref().ok_or_else(|| {
+                    Error::new(
+                        ident.span(),
+                        "`max_variant_size` can only be asserted on enums",
+                    )
+                })?;
+                let variants: Vec<_> = variants
+                    .iter()
+                    .map(|(variant, field_tys)| {
+                        let field_tys = field_tys
+                            .iter()
+                            .map(|field_ty| {
+                                self.concrete(field_ty.clone(), ty, instance)
+                            })
+                            .collect();
+                        (variant, field_tys)
+                    })
+                    .collect();
+                let span = span.unwrap_or_else(|| ident.span());
+                expand_max_variant_size(max, ty, &variants, span)
+                    .to_tokens(&mut self.consts);
+            }
+            Predicate::Instances(_) | Predicate::Quantified(_) => {
+                unreachable!("nested `for` predicates are rejected when parsed")
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `tokens` from the item's definition as they apply to `ty`, the
+    /// type being checked, with lifetimes replaced by `'static`, the
+    /// parameters of an instance replaced by its arguments, and `Self`
+    /// replaced by `ty`.
+    fn concrete(
+        &self,
+        tokens: TokenStream,
+        ty: &TokenStream,
+        instance: Option<(&[Ident], &Instance)>,
+    ) -> TokenStream {
+        let lifetimes: Vec<&Ident> = self.lifetimes.iter().collect();
+        let tokens = static_lifetimes(tokens, &lifetimes);
+        let tokens = match instance {
+            Some((params, instance)) => {
+                instantiate(tokens, params, &instance.args)
+            }
+            None => tokens,
+        };
+        // `Self` isn't allowed in the constants that checks are made of.
+        let self_ident = Ident::new("Self", Span::call_site());
+        instantiate(tokens, &[self_ident], &[ty])
+    }
+
+    /// Returns the fields of the item for a `predicate` that only applies to
+    /// structs, with their types as given by `concrete`.
+    fn struct_fields(
+        &self,
+        predicate: &Ident,
+        ty: &TokenStream,
+        instance: Option<(&[Ident], &Instance)>,
+    ) -> Result<Vec<(&Member, TokenStream)>> {
+        let fields = self.fields.as_ref().ok_or_else(|| {
+            Error::new(
+                predicate.span(),
+                format!("`{}` can only be asserted on structs", predicate),
+            )
+        })?;
+        let fields = fields.iter().map(|(member, field_ty)| {
+            (member, self.concrete(field_ty.clone(), ty, instance))
+        });
+        Ok(fields.collect())
+    }
+
+    fn finish(self) -> TokenStream {
+        let Checks {
+            mut consts, impls, ..
+        } = self;
+        if !impls.is_empty() {
+            // All trait checks share a single set of type-level booleans.
+            let prelude = trait_expr::prelude();
+            consts.extend(quote! {
+                const _: () = {
+                    #prelude
+                    #impls
+                };
+            });
+        }
+        consts
+    }
+}
+
+/// Removes the `#[assert(...)]` attributes from the fields of `item` after the
+/// item's own attribute failed to parse, adding their errors to `error`.
+pub fn discard(item: &mut DeriveInput, mut error: Error) -> Error {
+    if let Err(fields) = take_field_args(item) {
+        error.combine(fields);
+    }
+    error
+}
+
+/// A field annotated with `#[assert(...)]`.
+struct Field {
+    member: Member,
+    ty: Type,
+    args: FieldArgs,
+}
+
+/// Removes the `#[assert(...)]` attributes from the fields of `item` and
+/// parses them.
+///
+/// Every attribute is removed even if some fail to parse.
+fn take_field_args(item: &mut DeriveInput) -> Result<Vec<Field>> {
+    let (fields, is_enum): (Vec<&mut syn::Field>, _) = match &mut item.data {
+        Data::Struct(data) => (data.fields.iter_mut().collect(), false),
+        Data::Union(data) => (data.fields.named.iter_mut().collect(), false),
+        Data::Enum(data) => {
+            let fields = data.variants.iter_mut().flat_map(|v| &mut v.fields);
+            (fields.collect(), true)
+        }
+    };
+
+    let mut result = Vec::new();
+    let mut errors: Option<Error> = None;
+    for (index, field) in fields.into_iter().enumerate() {
+        let (attrs, rest) = mem::take(&mut field.attrs)
+            .into_iter()
+            .partition::<Vec<_>, _>(|attr| attr.path().is_ident("assert"));
+        field.attrs = rest;
+
+        let member = field_member(index, field);
+        for attr in attrs {
+            let args = if is_enum {
+                Err(Error::new_spanned(
+                    &attr,
+                    "fields can only be asserted on structs and unions",
+                ))
+            } else {
+                attr.parse_args::<FieldArgs>()
+            };
+            match args {
+                Ok(args) => result.push(Field {
+                    member: member.clone(),
+                    ty: field.ty.clone(),
+                    args,
+                }),
+                Err(error) => match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                },
+            }
+        }
+    }
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(result),
+    }
+}
+
+/// Checks the offset, size, and alignment of a field of `item`.
+fn expand_field(item: &DeriveInput, field: &Field) -> Result<TokenStream> {
+    let ty = item_ty(item, |param, _| match param {
+        GenericParam::Lifetime(_) => Ok(quote!('static)),
+        _ => Err(Error::new_spanned(
+            &field.args.predicates,
+            "cannot check fields of a generic type",
+        )),
+    })?;
+    let lifetimes = item_lifetimes(item);
+    let lifetimes: Vec<&Ident> = lifetimes.iter().collect();
+    let field_ty = static_lifetimes(field.ty.to_token_stream(), &lifetimes);
+
+    let member = &field.member;
+    let subject = format!("{}.{}", item.ident, member.to_token_stream());
+    let mut checks = TokenStream::new();
+    for predicate in &field.args.predicates {
+        let property = &predicate.property;
+        let span = property.ident.span();
+        let mem_fn = property.mem_fn();
+        let actual = match property.kind {
+            PropertyKind::Offset => {
+                quote_spanned!(span=> ::core::mem::offset_of!(#ty, #member))
+            }
+            _ => quote_spanned!(span=> ::core::mem::#mem_fn::<#field_ty>()),
+        };
+        checks.extend(expand_layout(predicate, &subject, actual, span));
+    }
+    Ok(checks)
+}
+
+/// Replaces each of `params` in `tokens` with the corresponding argument.
+fn instantiate<A: ToTokens>(
+    tokens: TokenStream,
+    params: &[Ident],
+    args: &[A],
+) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => {
+                match params.iter().position(|p| *p == ident) {
+                    Some(index) => {
+                        let arg = &args[index];
+                        // Grouped so that arguments like `&T` stay intact.
+                        let mut group =
+                            Group::new(Delimiter::None, quote!(#arg));
+                        group.set_span(ident.span());
+                        TokenTree::Group(group)
+                    }
+                    None => TokenTree::Ident(ident),
+                }
+            }
+            TokenTree::Group(group) => {
+                let stream = instantiate(group.stream(), params, args);
+                let mut new = Group::new(group.delimiter(), stream);
+                new.set_span(group.span());
+                TokenTree::Group(new)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+/// Returns how the field at `index` is accessed.
+fn field_member(index: usize, field: &syn::Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index {
+            index: index as u32,
+            span: field.ty.span(),
+        }),
+    }
+}
+
+/// Returns the members and types of the fields of `item`, if it's a struct.
+fn struct_fields(item: &DeriveInput) -> Option<Vec<(Member, TokenStream)>> {
+    let Data::Struct(data) = &item.data else {
+        return None;
+    };
+    let fields = data.fields.iter().enumerate().map(|(index, field)| {
+        (field_member(index, field), field.ty.to_token_stream())
+    });
+    Some(fields.collect())
+}
+
+/// Returns the variants of `item` and the types of their fields, if it's an
+/// enum.
+fn enum_variants(item: &DeriveInput) -> Option<Vec<(Ident, Vec<TokenStream>)>> {
+    let Data::Enum(data) = &item.data else {
+        return None;
+    };
+    let variants = data.variants.iter().map(|variant| {
+        let field_tys = variant
+            .fields
+            .iter()
+            .map(|field| field.ty.to_token_stream())
+            .collect();
+        (variant.ident.clone(), field_tys)
+    });
+    Some(variants.collect())
+}
+
+/// Returns the names of the lifetime parameters of `item`.
+fn item_lifetimes(item: &DeriveInput) -> Vec<Ident> {
+    item.generics
+        .lifetimes()
+        .map(|param| param.lifetime.ident.clone())
+        .collect()
+}
+
+/// Replaces each of `lifetimes` in `tokens` with `'static`.
+fn static_lifetimes(tokens: TokenStream, lifetimes: &[&Ident]) -> TokenStream {
+    let mut output = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                match tokens.peek() {
+                    Some(TokenTree::Ident(ident))
+                        if lifetimes.contains(&ident) =>
+                    {
+                        tokens.next();
+                        output.extend(quote_spanned!(punct.span()=> 'static));
+                    }
+                    _ => output.extend(Some(TokenTree::Punct(punct))),
+                }
+            }
+            TokenTree::Group(group) => {
+                let stream = static_lifetimes(group.stream(), lifetimes);
+                let mut new = Group::new(group.delimiter(), stream);
+                new.set_span(group.span());
+                output.extend(Some(TokenTree::Group(new)));
+            }
+            token => output.extend(Some(token)),
+        }
+    }
+    output
+}
+
+/// Returns the type being asserted over, which must not be generic over
+/// anything but lifetimes.
+fn self_ty(item: &DeriveInput, predicate: &Predicate) -> Result<TokenStream> {
+    item_ty(item, |param, _| match param {
+        GenericParam::Lifetime(_) => Ok(quote!('static)),
+        _ => Err(Error::new_spanned(
+            predicate,
+            "cannot measure a generic type without concrete parameters; \
+             instantiate it with `for <params> in [<args>, ...]: <predicate>`",
+        )),
+    })
+}
+
+/// Returns the item instantiated with the arguments of `instance`.
+fn instance_ty(
+    item: &DeriveInput,
+    predicate: &InstancesPredicate,
+    instance: &Instance,
+) -> Result<TokenStream> {
+    for param in &predicate.params {
+        if !item.generics.params.iter().any(|p| param_ident(p) == param) {
+            return Err(Error::new(
+                param.span(),
+                format!(
+                    "`{}` has no generic parameter `{}`",
+                    item.ident, param
+                ),
+            ));
+        }
+    }
+    item_ty(item, |param, args| match param {
+        GenericParam::Lifetime(_) => Ok(quote!('static)),
+        _ => {
+            let ident = param_ident(param);
+            match predicate.params.iter().position(|p| p == ident) {
+                Some(index) => Ok(instance.args[index].to_token_stream()),
+                None => param_default(item, param, args).ok_or_else(|| {
+                    Error::new_spanned(
+                        &predicate.params[0],
+                        format!(
+                            "generic parameter `{}` is not instantiated",
+                            ident
+                        ),
+                    )
+                }),
+            }
+        }
+    })
+}
+
+/// Returns `item` with every generic parameter replaced via `arg`, which is
+/// also given the arguments of the parameters before it.
+fn item_ty(
+    item: &DeriveInput,
+    arg: impl Fn(&GenericParam, &[TokenStream]) -> Result<TokenStream>,
+) -> Result<TokenStream> {
+    let ident = &item.ident;
+    if item.generics.params.is_empty() {
+        return Ok(ident.to_token_stream());
+    }
+    let mut args = Vec::new();
+    for param in &item.generics.params {
+        let arg = arg(param, &args)?;
+        args.push(arg);
+    }
+    Ok(quote!(#ident<#(#args),*>))
+}
+
+fn param_ident(param: &GenericParam) -> &Ident {
+    match param {
+        GenericParam::Lifetime(param) => &param.lifetime.ident,
+        GenericParam::Type(param) => &param.ident,
+        GenericParam::Const(param) => &param.ident,
+    }
+}
+
+/// Returns `param` as it's passed as a generic argument.
+fn param_arg(param: &GenericParam) -> TokenStream {
+    match param {
+        GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        _ => param_ident(param).to_token_stream(),
+    }
+}
+
+/// Returns the default argument of a type or const parameter of `item`, if
+/// any, with the parameters before it replaced by their `args`.
+fn param_default(
+    item: &DeriveInput,
+    param: &GenericParam,
+    args: &[TokenStream],
+) -> Option<TokenStream> {
+    let default = match param {
+        GenericParam::Lifetime(_) => return None,
+        GenericParam::Type(param) => param.default.as_ref()?.to_token_stream(),
+        GenericParam::Const(param) => {
+            let default = param.default.as_ref()?;
+            // Const arguments other than literals and paths must be braced.
+            quote!({ #default })
+        }
+    };
+    let mut lifetimes = Vec::new();
+    let mut params = Vec::new();
+    let mut param_args = Vec::new();
+    for (param, arg) in item.generics.params.iter().zip(args) {
+        match param {
+            // Lifetimes are either kept or replaced by `'static`.
+            GenericParam::Lifetime(lifetime) => {
+                if arg.to_string() != param_arg(param).to_string() {
+                    lifetimes.push(&lifetime.lifetime.ident);
+                }
+            }
+            _ => {
+                params.push(param_ident(param).clone());
+                param_args.push(arg);
+            }
+        }
+    }
+    let default = static_lifetimes(default, &lifetimes);
+    Some(instantiate(default, &params, &param_args))
+}
+
+/// Checks that the `actual` measurement of `subject` satisfies the
+/// predicate's comparison.
+fn expand_layout(
+    predicate: &LayoutPredicate,
+    subject: &str,
+    actual: TokenStream,
+    span: Span,
+) -> TokenStream {
+    let cmp = &predicate.cmp;
+    let value = &predicate.value;
+    let message = format!(
+        "`{}` does not satisfy `{}`",
+        subject,
+        predicate.to_token_stream(),
+    );
+    quote_spanned! {span=>
+        const _: () = ::core::assert!(#actual #cmp #value, "{}", #message);
+    }
+}
+
+/// Checks that `ty` has the same size and alignment as `inner`.
+///
+/// On failure, the size or alignment of `ty` is revealed through an array
+/// length mismatch.
+fn expand_transparent(
+    ty: &TokenStream,
+    inner: &TokenStream,
+    span: Span,
+) -> TokenStream {
+    let checks = [("size_of", "size"), ("align_of", "alignment")];
+    let checks = checks.iter().map(|(mem_fn, property)| {
+        let mem_fn = Ident::new(mem_fn, span);
+        let message = format!(
+            "`{}` does not have the same {} as `{}`",
+            ty,
+            property,
+            inner,
+        );
+        quote_spanned! {span=>
+            const _: () = ::core::assert!(
+                ::core::mem::#mem_fn::<#ty>() == ::core::mem::#mem_fn::<#inner>(),
+                "{}",
+                #message,
+            );
+            const _: [(); ::core::mem::#mem_fn::<#inner>()] =
+                [(); ::core::mem::#mem_fn::<#ty>()];
+        }
+    });
+    checks.collect()
+}
+
+/// Checks that `ty` has no padding, given all of its fields.
+///
+/// Each field must end where another one starts or where `ty` ends, and the
+/// size of `ty` is revealed through an array length mismatch if it's larger
+/// than the sum of its fields.
+fn expand_no_padding(
+    ty: &TokenStream,
+    fields: &[(&Member, TokenStream)],
+    span: Span,
+) -> TokenStream {
+    let members: Vec<_> = fields.iter().map(|(member, _)| member).collect();
+    let field_tys: Vec<_> = fields.iter().map(|(_, ty)| ty).collect();
+    let messages = members.iter().map(|member| {
+        format!("`{}` has padding after `{}`", ty, member.to_token_stream())
+    });
+    let fields_size = if field_tys.is_empty() {
+        quote!(0)
+    } else {
+        quote!(#(::core::mem::size_of::<#field_tys>())+*)
+    };
+    let message = format!("`{}` is larger than its fields", ty);
+    // Everything is inlined, since named items here would hide user items
+    // that the field types refer to.
+    let boundaries =
+        field_tys.iter().zip(&members).map(|(field_ty, member)| {
+            let end = quote! {
+                ::core::mem::offset_of!(#ty, #member)
+                    + ::core::mem::size_of::<#field_ty>()
+            };
+            quote! {
+                #end == ::core::mem::size_of::<#ty>()
+                    #(|| #end == ::core::mem::offset_of!(#ty, #members))*
+            }
+        });
+    quote_spanned! {span=>
+        #(const _: () = ::core::assert!(#boundaries, "{}", #messages);)*
+
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#ty>() == #fields_size,
+            "{}",
+            #message,
+        );
+        const _: [(); #fields_size] = [(); ::core::mem::size_of::<#ty>()];
+    }
+}
+
+/// Checks that each field is at an offset that's a multiple of its type's
+/// alignment.
+///
+/// On failure, the offset of the field is revealed along with the nearest
+/// aligned offset before it through an array length mismatch.
+fn expand_naturally_aligned(
+    ty: &TokenStream,
+    fields: &[(&Member, TokenStream)],
+    span: Span,
+) -> TokenStream {
+    fields
+        .iter()
+        .map(|(member, field_ty)| {
+            let message = format!(
+                "`{}.{}` is not aligned to `{}`",
+                ty,
+                member.to_token_stream(),
+                field_ty,
+            );
+            let offset = quote!(::core::mem::offset_of!(#ty, #member));
+            let align = quote!(::core::mem::align_of::<#field_ty>());
+            quote_spanned! {span=>
+                // `is_multiple_of` is too recent, and alignments may be 1.
+                #[allow(
+                    unknown_lints,
+                    clippy::manual_is_multiple_of,
+                    clippy::modulo_one
+                )]
+                const _: () = {
+                    ::core::assert!(#offset % #align == 0, "{}", #message);
+                    const _: [(); #offset - #offset % #align] = [(); #offset];
+                };
+            }
+        })
+        .collect()
+}
+
+/// Checks that the fields of each variant, as a tuple, fit within the limit.
+///
+/// On failure, the number of bytes by which a variant exceeds the limit is
+/// revealed through an array length mismatch.
+fn expand_max_variant_size(
+    predicate: &MaxVariantSizePredicate,
+    ty: &TokenStream,
+    variants: &[(&Ident, Vec<TokenStream>)],
+    span: Span,
+) -> TokenStream {
+    let value = &predicate.value;
+    let checks = variants.iter().map(|(variant, field_tys)| {
+        let message = format!(
+            "`{}::{}` is larger than `{}` bytes",
+            ty,
+            variant,
+            value.to_token_stream(),
+        );
+        let size = quote!(::core::mem::size_of::<(#(#field_tys,)*)>());
+        quote_spanned! {span=>
+            const _: () = ::core::assert!(#size <= #value, "{}", #message);
+            const _: [(); 0] = [(); #size.saturating_sub(#value)];
+        }
+    });
+    checks.collect()
+}
+
+/// Returns an error unless `item` is `#[repr(transparent)]`.
+fn check_repr_transparent(
+    item: &DeriveInput,
+    predicate: &TransparentPredicate,
+) -> Result<()> {
+    for attr in &item.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        let reprs = attr
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        if reprs.iter().any(|repr| repr.path().is_ident("transparent")) {
+            return Ok(());
+        }
+    }
+    Err(Error::new(
+        predicate.ident.span(),
+        format!("`{}` is not `#[repr(transparent)]`", item.ident),
+    ))
+}
+
+/// Checks that `ty` satisfies the predicate's trait expression.
+///
+/// This fails with a `True`/`False` type mismatch just like `assert_impl!`.
+fn expand_impl(
+    predicate: &ImplPredicate,
+    ty: &TokenStream,
+    span: Span,
+) -> TokenStream {
+    let expr = respan(predicate.expr.expand(ty), span);
+    quote_spanned! {span=>
+        const _: True = *#expr;
+    }
+}
+
+/// Checks that the item satisfies a trait expression for all generic
+/// parameters declared by the predicate.
+fn expand_quantified(
+    item: &DeriveInput,
+    predicate: &QuantifiedPredicate,
+) -> Result<TokenStream> {
+    let declared = &predicate.generics.params;
+    let ty = item_ty(item, |param, args| {
+        let ident = param_ident(param);
+        if declared.iter().any(|p| param_ident(p) == ident) {
+            return Ok(param_arg(param));
+        }
+        match param {
+            GenericParam::Lifetime(_) => Ok(quote!('static)),
+            _ => param_default(item, param, args).ok_or_else(|| {
+                Error::new_spanned(
+                    &predicate.generics,
+                    format!("generic parameter `{}` is not declared", ident),
+                )
+            }),
+        }
+    })?;
+    let span = predicate.self_token.span;
+    let expr = respan(predicate.expr.expand(&ty), span);
+    let (generics, _, where_clause) = predicate.generics.split_for_impl();
+    Ok(quote_spanned! {span=>
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_impl #generics () #where_clause {
+                let _: True = *#expr;
+            }
+        };
+    })
+}
+
+/// Sets the span of every token in `tokens` so that errors point at `span`.
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let stream = respan(group.stream(), span);
+                let mut group = Group::new(group.delimiter(), stream);
+                group.set_span(span);
+                token = TokenTree::Group(group);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
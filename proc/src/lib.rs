@@ -25,14 +25,14 @@
 //!
 //! ```toml
 //! [dependencies]
-//! proc_static_assertions = "0.0.0"
+//! proc_static_assertions_next = "0.0.1"
 //! ```
 //!
 //! and this to your crate root (`main.rs` or `lib.rs`):
 //!
 //! ```
 //! #[macro_use]
-//! extern crate proc_static_assertions;
+//! extern crate proc_static_assertions_next;
 //! # fn main() {}
 //! ```
 //!
@@ -42,17 +42,19 @@
 //!
 //! ```toml
 //! [dependencies]
-//! static_assertions = { version = "1.1.0", features = ["proc"] }
+//! static_assertions_next = { version = "1.1.2", features = ["proc"] }
 //! ```
 //!
 //! and this to your crate root (`main.rs` or `lib.rs`):
 //!
 //! ```ignore
 //! #[macro_use]
-//! extern crate static_assertions;
+//! extern crate static_assertions_next;
 //! ```
 //!
-//! This will also import all macros in `proc_static_assertions`.
+//! This will also import all macros in `proc_static_assertions_next`, which
+//! can alternatively be referred to by path, e.g.
+//! `static_assertions_next::assert`.
 //!
 //! # Donate
 //!
@@ -81,12 +83,341 @@
 
 extern crate proc_macro;
 use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+mod args;
+mod derive;
+mod expand;
+mod trait_expr;
 
 /// Statically assert aspects of types, traits, and more.
 ///
-/// This currently does nothing. Create an issue if you have ideas for what this
-/// could do!
+/// The annotated item is emitted unchanged, followed by a compile-time check
+/// for every comma-separated predicate.
+///
+/// # Syntax
+///
+/// ```skip
+/// #[assert(<predicate>, ...)]
+/// ```
+///
+/// where `<predicate>` is one of:
+///
+/// - `size <cmp> <expr>`: compares [`size_of`] of the item against `<expr>`
+///
+/// - `align <cmp> <expr>`: compares [`align_of`] of the item against `<expr>`
+///
+/// - `impl(<trait_expr>)`: requires the item to satisfy `<trait_expr>`, which
+///   is made out of trait paths combined with `!` for negation, `&` for
+///   conjunction, `|` for disjunction and parentheses for grouping
+///
+/// - `transparent`: requires the item to be `#[repr(transparent)]`, so that it
+///   has the same layout and ABI as its only non-zero-sized field
+///
+/// - `transparent(<type>)`: additionally compares [`size_of`] and [`align_of`]
+///   of the item against those of `<type>`, which is expected to be wrapped
+///   and may refer to the parameters of an enclosing `for` predicate
+///
+/// - `no_padding`: requires every field of a struct to end where another one
+///   starts or where the struct ends, so that it has no padding bytes
+///
+/// - `naturally_aligned`: requires every field of a struct to be at an offset
+///   that's a multiple of its type's alignment, which is mainly useful for
+///   `#[repr(packed)]` structs
+///
+/// - `max_variant_size = <expr>`: requires the fields of each variant of an
+///   enum to take up at most `<expr>` bytes, measured as a tuple
+///
+/// - `for <params> in [<args>, ...]: <predicate>`: checks `<predicate>` for
+///   each listed instantiation of a generic item, where `<params>` is either a
+///   single parameter like `T` or a parenthesized list like `(K, V)` with
+///   matching argument lists like `(u32, String)`
+///
+/// - `for<<generics>> Self: <trait_expr>`: requires the item to satisfy
+///   `<trait_expr>` for all generic parameters satisfying the given bounds
+///
+/// `<cmp>` is any of `==`, `!=`, `<`, `<=`, `>`, or `>=`, and `<expr>` is a
+/// constant `usize` expression, such as arithmetic over literals and `const`
+/// items.
+///
+/// Trait expressions follow the same grammar as `assert_impl!`, except that
+/// traits with generic arguments like `From<u8>` don't need parentheses. Since
+/// `assert_impl!` groups `x & y | z` as `x & (y | z)` rather than by
+/// precedence, mixing `&` and `|` requires parentheses.
+///
+/// Fields of structs and unions can be annotated with their own
+/// `#[assert(...)]` attribute, which takes `offset <cmp> <expr>` predicates
+/// comparing [`offset_of!`] of the field as well as `size` and `align`
+/// predicates over the field's type. These attributes are removed from the
+/// emitted item.
+///
+/// Layout and trait predicates on a generic item must be wrapped in `for`,
+/// since there is no concrete type to check otherwise. Lifetime parameters
+/// are always replaced by `'static`, and parameters with defaults that aren't
+/// listed take on their default.
+///
+/// # Examples
+///
+/// A `u32` wrapper has the same size and alignment as `u32`:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(size == 4, align == 4)]
+/// struct Foo {
+///     value: i32,
+/// }
+/// ```
+///
+/// Constants and arithmetic can be used on the right-hand side:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// const HEADER: usize = 8;
+///
+/// #[assert(size <= HEADER * 2, align != 1)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+/// }
+/// ```
+///
+/// Trait contracts can be kept next to the type they apply to:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// use std::marker::PhantomPinned;
+///
+/// #[assert(impl(Send & Sync & !Unpin), size <= 64)]
+/// struct Task {
+///     id: u64,
+///     _pin: PhantomPinned,
+/// }
+/// ```
+///
+/// Wire formats can have their offsets checked field by field:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(size == 12)]
+/// #[repr(C)]
+/// struct Packet {
+///     #[assert(offset == 0, size == 4)]
+///     magic: u32,
+///     #[assert(offset == 4)]
+///     version: u16,
+///     flags: u16,
+///     #[assert(offset == 8, size == 4)]
+///     header_len: u32,
+/// }
+/// ```
+///
+/// Newtypes passed across FFI can be checked against the types they wrap:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// use std::num::NonZeroU64;
+///
+/// #[assert(transparent(f64))]
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// #[assert(transparent(NonZeroU64))]
+/// #[repr(transparent)]
+/// struct UserId(NonZeroU64);
+/// ```
+///
+/// Types that are copied onto the wire can be kept free of padding, which
+/// would otherwise leak uninitialized memory:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(no_padding, size == 8)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+///     flags: u16,
+/// }
+/// ```
+///
+/// Packed structs can be laid out so that their fields stay aligned:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(naturally_aligned, no_padding)]
+/// #[repr(C, packed)]
+/// struct Frame {
+///     kind: u8,
+///     flags: u8,
+///     len: u16,
+///     crc: u32,
+/// }
+/// ```
+///
+/// Large enum variants can be ruled out regardless of lint settings:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(max_variant_size = 16)]
+/// enum Event {
+///     Click(u32, u32),
+///     Key { code: u32, shift: bool },
+///     Paste(Box<str>),
+///     Quit,
+/// }
+/// ```
+///
+/// Generic items are checked for every listed set of arguments, and errors
+/// point at the arguments that fail:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(for T in [u8, u64, String]: size <= 32)]
+/// struct Slot<T>(Option<T>, u32);
+///
+/// #[assert(for (K, V) in [(u8, u8), (u32, &str)]: impl(Copy))]
+/// #[derive(Clone, Copy)]
+/// struct Entry<K, V> {
+///     key: K,
+///     value: V,
+/// }
+/// ```
+///
+/// Bounds declared with `for<...>` hold for every possible argument:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(for<T: Send> Self: Send, for<T: Sync> Self: Sync)]
+/// struct Wrapper<T>(Vec<T>);
+/// ```
+///
+/// The following example fails to compile because `Slot<String>` takes up at
+/// least 16 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(for T in [u8, String]: size < 16)]
+/// struct Slot<T>(Option<T>, u32);
+/// ```
+///
+/// The following example fails to compile because `Meters` has the same layout
+/// as `f64` but is missing `#[repr(transparent)]`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(transparent(f64))]
+/// struct Meters(f64);
+/// ```
+///
+/// The following example fails to compile because `Header` has 2 bytes of
+/// padding after `len`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(no_padding)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+///     crc: u32,
+/// }
+/// ```
+///
+/// The following example fails to compile because raw pointers are neither
+/// [`Send`] nor [`Sync`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(impl(Send | Sync))]
+/// struct Ptr(*const u8);
+/// ```
+///
+/// The following example fails to compile because `&` and `|` are mixed
+/// without parentheses, even though `Id` satisfies either grouping:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(impl(Send & Sync | Copy))]
+/// #[derive(Clone, Copy)]
+/// struct Id(u32);
+/// ```
+///
+/// The following example fails to compile because `Bytes` takes up 16 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions_next; fn main() {}
+/// #[assert(size < 16)]
+/// struct Bytes([u8; 16]);
+/// ```
+///
+/// [`size_of`]: https://doc.rust-lang.org/core/mem/fn.size_of.html
+/// [`align_of`]: https://doc.rust-lang.org/core/mem/fn.align_of.html
+/// [`offset_of!`]: https://doc.rust-lang.org/core/mem/macro.offset_of.html
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 #[proc_macro_attribute]
-pub fn assert(_attr: TokenStream, _item: TokenStream) -> TokenStream {
-    TokenStream::new()
+pub fn assert(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(item as DeriveInput);
+    // The item is kept even if the checks are malformed so that errors don't
+    // cascade into every use of it.
+    let checks = match syn::parse::<args::Args>(attr) {
+        Ok(args) => expand::expand(&args, &mut item),
+        Err(error) => Err(expand::discard(&mut item, error)),
+    }
+    .unwrap_or_else(syn::Error::into_compile_error);
+    quote!(#item #checks).into()
+}
+
+/// Implements `static_assertions_next::Zeroable` for a struct or union after
+/// checking that every field is `Zeroable`.
+///
+/// This requires `static_assertions_next` as a dependency, which can be given
+/// another path with `#[bits(crate = <path>)]` if it's renamed or re-exported.
+/// Errors name the fields that aren't valid when zeroed, such as `bool`,
+/// `char`, references, and `NonZero*` integers. Enums are rejected, and each
+/// type parameter is required to be `Zeroable`.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     flags: [u8; 4],
+///     next: *const Header,
+/// }
+/// ```
+#[proc_macro_derive(Zeroable, attributes(bits))]
+pub fn derive_zeroable(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+    derive::expand(&item, derive::BitTrait::Zeroable)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `static_assertions_next::AnyBitPattern` for a struct or union
+/// after checking that every field is `AnyBitPattern`.
+///
+/// This works like [`Zeroable`](derive.Zeroable.html), which must also be
+/// implemented since it's a supertrait. Errors name the fields that aren't
+/// valid for any bit pattern, such as `bool` and `char`.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(Zeroable, AnyBitPattern)]
+/// #[repr(C)]
+/// struct Sample {
+///     time: u64,
+///     channels: [f32; 2],
+/// }
+/// ```
+#[proc_macro_derive(AnyBitPattern, attributes(bits))]
+pub fn derive_any_bit_pattern(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+    derive::expand(&item, derive::BitTrait::AnyBitPattern)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
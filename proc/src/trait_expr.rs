@@ -0,0 +1,175 @@
+//! Boolean expressions over trait implementations, as in `assert_impl!`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    token, Path, Result, Token,
+};
+
+/// A logical expression of traits combined with `!`, `&`, `|`, and
+/// parentheses.
+///
+/// Since `assert_impl!` groups operators to the right rather than by
+/// precedence, mixing `&` and `|` requires parentheses so that expressions
+/// can't mean different things in each. Unlike with `assert_impl!`, traits
+/// with generic arguments don't need to be parenthesized.
+pub enum TraitExpr {
+    Trait(Path),
+    Not(Box<TraitExpr>),
+    And(Box<TraitExpr>, Box<TraitExpr>),
+    Or(Box<TraitExpr>, Box<TraitExpr>),
+}
+
+impl Parse for TraitExpr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut expr = parse_unary(input)?;
+        let is_and = input.peek(Token![&]);
+        while input.peek(Token![&]) || input.peek(Token![|]) {
+            if input.peek(Token![&]) != is_and {
+                return Err(input
+                    .error("`&` and `|` cannot be mixed without parentheses"));
+            }
+            if is_and {
+                input.parse::<Token![&]>()?;
+                let right = parse_unary(input)?;
+                expr = TraitExpr::And(Box::new(expr), Box::new(right));
+            } else {
+                input.parse::<Token![|]>()?;
+                let right = parse_unary(input)?;
+                expr = TraitExpr::Or(Box::new(expr), Box::new(right));
+            }
+        }
+        Ok(expr)
+    }
+}
+
+fn parse_unary(input: ParseStream) -> Result<TraitExpr> {
+    if input.peek(Token![!]) {
+        input.parse::<Token![!]>()?;
+        Ok(TraitExpr::Not(Box::new(parse_unary(input)?)))
+    } else if input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        content.parse()
+    } else {
+        input.parse().map(TraitExpr::Trait)
+    }
+}
+
+impl ToTokens for TraitExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            TraitExpr::Trait(path) => path.to_tokens(tokens),
+            TraitExpr::Not(expr) => {
+                Token![!](Span::call_site()).to_tokens(tokens);
+                expr.to_tokens_grouped(tokens);
+            }
+            TraitExpr::And(left, right) => {
+                left.to_tokens_grouped(tokens);
+                Token![&](Span::call_site()).to_tokens(tokens);
+                right.to_tokens_grouped(tokens);
+            }
+            TraitExpr::Or(left, right) => {
+                left.to_tokens_grouped(tokens);
+                Token![|](Span::call_site()).to_tokens(tokens);
+                right.to_tokens_grouped(tokens);
+            }
+        }
+    }
+}
+
+impl TraitExpr {
+    /// Emits binary expressions within parentheses to preserve grouping.
+    fn to_tokens_grouped(&self, tokens: &mut TokenStream) {
+        match self {
+            TraitExpr::And(..) | TraitExpr::Or(..) => {
+                token::Paren::default().surround(tokens, |tokens| {
+                    self.to_tokens(tokens);
+                });
+            }
+            _ => self.to_tokens(tokens),
+        }
+    }
+
+    /// Evaluates to a `&True` or `&False` depending on whether `ty` satisfies
+    /// this expression.
+    ///
+    /// The types used here are declared by [`prelude`].
+    pub fn expand(&self, ty: &dyn ToTokens) -> TokenStream {
+        match self {
+            TraitExpr::Trait(path) => quote! {{
+                // Base case: computes whether `ty` implements `path`.
+                struct Wrapper<T: ?Sized>(::core::marker::PhantomData<T>);
+
+                #[allow(dead_code)]
+                impl<T: ?Sized + #path> Wrapper<T> {
+                    const DOES_IMPL: True = True;
+                }
+
+                // If `ty: path`, the inherent constant on `Wrapper` is used and
+                // is `True`. Otherwise, the `DoesntImpl` fallback is `False`.
+                &<Wrapper<#ty>>::DOES_IMPL
+            }},
+            TraitExpr::Not(expr) => {
+                let expr = expr.expand(ty);
+                quote!(#expr.not())
+            }
+            TraitExpr::And(left, right) => {
+                let (left, right) = (left.expand(ty), right.expand(ty));
+                quote!(#left.and(#right))
+            }
+            TraitExpr::Or(left, right) => {
+                let (left, right) = (left.expand(ty), right.expand(ty));
+                quote!(#left.or(#right))
+            }
+        }
+    }
+}
+
+/// Type-level booleans needed by [`TraitExpr::expand`].
+///
+/// These mirror the hidden `_bool` module of `static_assertions`, which can't
+/// be referred to from here since this crate may be used on its own.
+pub fn prelude() -> TokenStream {
+    quote! {
+        #[derive(Clone, Copy)]
+        struct True;
+        #[derive(Clone, Copy)]
+        struct False;
+
+        #[allow(dead_code)]
+        impl True {
+            const fn not(&self) -> &'static False {
+                &False
+            }
+            const fn and<'a, T>(&self, other: &'a T) -> &'a T {
+                other
+            }
+            const fn or<T>(&self, _: &T) -> &'static True {
+                &True
+            }
+        }
+
+        #[allow(dead_code)]
+        impl False {
+            const fn not(&self) -> &'static True {
+                &True
+            }
+            const fn and<T>(&self, _: &T) -> &'static False {
+                &False
+            }
+            const fn or<'a, T>(&self, other: &'a T) -> &'a T {
+                other
+            }
+        }
+
+        // Fallback trait that returns `False` if the type does not implement a
+        // given trait.
+        trait DoesntImpl {
+            const DOES_IMPL: False = False;
+        }
+        impl<T: ?Sized> DoesntImpl for T {}
+    }
+}
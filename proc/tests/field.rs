@@ -0,0 +1,55 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+#[assert(size == 16, align == 4)]
+#[repr(C)]
+struct Header {
+    #[assert(offset == 0, size == 4)]
+    magic: u32,
+    #[assert(offset == 4)]
+    #[assert(size == 2, align == 2)]
+    version: u16,
+    flags: u16,
+    /// Documentation and other attributes are kept.
+    #[assert(offset == 8, size == 4)]
+    #[allow(unused)]
+    header_len: u32,
+    #[assert(offset >= 12, offset < 16, size <= 4)]
+    checksum: [u8; 4],
+}
+
+#[assert]
+#[repr(C)]
+struct Pair(
+    #[assert(offset == 0)] u8,
+    #[assert(offset == 4, size == 4)] u32,
+);
+
+#[assert]
+struct Borrowed<'a> {
+    #[assert(size == core::mem::size_of::<usize>())]
+    bytes: &'a [u8; 4],
+}
+
+#[assert]
+union Word {
+    #[assert(offset == 0, size == 4)]
+    int: u32,
+    #[assert(offset == 0, align == 1)]
+    bytes: [u8; 4],
+}
+
+#[test]
+fn attributes_are_stripped() {
+    let header = Header {
+        magic: 1,
+        version: 2,
+        flags: 3,
+        header_len: 16,
+        checksum: [0; 4],
+    };
+    let Pair(a, b) = Pair(4, 5);
+    core::assert_eq!(header.header_len + a as u32 + b, 25);
+}
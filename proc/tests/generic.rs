@@ -0,0 +1,55 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+use core::marker::PhantomData;
+
+#[assert(for T in [u8, u16, u64]: size <= 24, for T in [u8]: size == 8)]
+struct Slot<T>(Option<T>, u32);
+
+#[assert(
+    for (K, V) in [(u8, u8), (u32, u64)]: size <= 16,
+    for (K, V) in [(u32, u8)]: align == 4,
+)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+#[assert(for T in [u16]: size == 2 * 4, for<T: Send> Self: Send)]
+struct Array<T, const N: usize = 4>([T; N]);
+
+#[assert(
+    for T in [u16, [u8; 2]]: size == 4,
+    for<T: Send> Self: Send,
+)]
+struct Pair<T, U = T>(T, U);
+
+#[assert(for T in [u16]: size == 2 * WORD, for<'a, T: Send + Sync + 'a> Self: Send)]
+struct Borrowed<'a, T, U = &'a T>(T, U, PhantomData<&'a ()>);
+
+#[assert(size == WORD, impl(Copy & Sync))]
+#[derive(Clone, Copy)]
+struct Ref<'a>(&'a u8);
+
+#[assert(for T in [u8, [u8; 4]]: size == WORD * 2, for<'a, T: Send + Sync + 'a> Self: Send)]
+struct Slice<'a, T>(&'a [T]);
+
+#[assert(
+    for<T> Self: Send & Sync,
+    for<T: ?Sized> Self: Copy,
+    for T in [str, [u8]]: impl(Sync & Unpin & !Default),
+)]
+struct Marker<T: ?Sized>(PhantomData<*const T>);
+
+unsafe impl<T: ?Sized> Send for Marker<T> {}
+unsafe impl<T: ?Sized> Sync for Marker<T> {}
+impl<T: ?Sized> Clone for Marker<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for Marker<T> {}
+
+const WORD: usize = core::mem::size_of::<usize>();
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+use core::{cell::Cell, marker::PhantomPinned};
+
+#[assert(impl(Send & Sync & !Unpin), size <= 64)]
+struct Task {
+    id: u64,
+    _pin: PhantomPinned,
+}
+
+#[assert(impl(Send & !Sync))]
+struct Counter(Cell<u32>);
+
+#[assert(impl(From<u8> | !Copy), impl(!(Copy & Clone)))]
+#[derive(Clone)]
+struct Byte(u8);
+
+impl From<u8> for Byte {
+    fn from(value: u8) -> Self {
+        Byte(value)
+    }
+}
+
+#[assert(impl((Copy & Clone) | Default), impl(Default | (Copy & Send)))]
+#[derive(Default)]
+struct Empty;
+
+#[assert(impl(core::fmt::Debug & !core::fmt::Display))]
+#[derive(Debug)]
+struct Dbg;
+
+#[assert(impl(Iterator<Item = u8>))]
+struct Bytes;
+
+impl Iterator for Bytes {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        None
+    }
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+#[assert(naturally_aligned, no_padding, size == 12)]
+#[repr(C, packed)]
+struct Frame {
+    kind: u8,
+    flags: u8,
+    len: u16,
+    crc: u32,
+    body: [u8; 3],
+    tail: u8,
+}
+
+#[assert(naturally_aligned)]
+struct Unpacked(u8, u64, u16);
+
+const OFFSET: usize = 2;
+const ALIGN: usize = 4;
+
+#[assert(naturally_aligned)]
+#[repr(C, packed(4))]
+struct Counted {
+    a: [u16; OFFSET],
+    b: [u32; ALIGN],
+}
+
+#[assert(naturally_aligned)]
+#[repr(C)]
+struct Link {
+    next: Option<&'static Self>,
+    tag: u8,
+}
+
+#[assert(for T in [u8, u16, u32]: naturally_aligned)]
+#[repr(C, packed)]
+struct Header<T> {
+    tag: T,
+    value: T,
+}
@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+#[assert(no_padding, size == 8)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    #[assert(offset == 4)]
+    len: u16,
+    flags: u16,
+}
+
+#[assert(no_padding)]
+struct Reordered(u8, u32, u16, u8);
+
+#[assert(no_padding)]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    value: u64,
+}
+
+#[assert(no_padding)]
+struct Empty;
+
+#[assert(no_padding)]
+struct Borrowed<'a> {
+    bytes: &'a [u8],
+}
+
+const SIZE: usize = 4;
+
+#[assert(no_padding)]
+#[repr(C)]
+struct Counted {
+    bytes: [u8; SIZE],
+    value: u32,
+}
+
+#[assert(no_padding)]
+#[repr(C)]
+struct Node {
+    next: *const Self,
+    value: usize,
+}
+
+#[assert(for T in [u16, u32, [u8; 3]]: no_padding)]
+#[repr(C)]
+struct Pair<T> {
+    a: T,
+    b: T,
+}
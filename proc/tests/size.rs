@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 #[macro_use]
 extern crate proc_static_assertions_next;
 
@@ -5,3 +7,31 @@ extern crate proc_static_assertions_next;
 struct Foo {
     value: i32,
 }
+
+const WORD: usize = core::mem::size_of::<usize>();
+
+#[assert(size == WORD * 2, align == WORD, size != 0)]
+struct Pair(usize, usize);
+
+#[assert(size > 1, size >= 2, size < 3, size <= 2,)]
+enum Small {
+    A(u8),
+    B(u8),
+}
+
+#[assert(align <= 4)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+#[assert]
+struct Unchecked<T>(T);
+
+#[test]
+fn item_is_preserved() {
+    let foo = Foo { value: 1 };
+    let Pair(a, b) = Pair(2, 3);
+    let _ = Unchecked(Small::A(0));
+    core::assert_eq!(foo.value + a as i32 + b as i32, 6);
+}
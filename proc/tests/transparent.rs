@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+use core::marker::PhantomData;
+use core::num::NonZeroU64;
+
+#[assert(transparent(f64))]
+#[repr(transparent)]
+struct Meters(f64);
+
+#[repr(transparent)]
+#[assert(transparent(NonZeroU64), size == 8)]
+struct UserId(NonZeroU64);
+
+#[assert(transparent(u32))]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+struct Tagged {
+    value: u32,
+    _marker: PhantomData<*const u8>,
+}
+
+#[assert(transparent, for T in [u8, String]: transparent(T))]
+#[repr(transparent)]
+struct Wrapper<T>(T);
+
+#[assert(transparent(&'a str))]
+#[repr(transparent)]
+struct Name<'a>(&'a str);
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate proc_static_assertions_next;
+
+#[assert(max_variant_size = 8, size == 12)]
+enum Event {
+    Click(u32, u32),
+    Key { code: u32, shift: bool },
+    Quit,
+}
+
+const LIMIT: usize = 16;
+
+#[assert(max_variant_size = LIMIT * 2)]
+enum Message<'a> {
+    Text(&'a str),
+    Bytes([u8; 32]),
+}
+
+#[assert(max_variant_size = 8)]
+enum List {
+    Cons(Box<Self>),
+    Nil,
+}
+
+#[assert(for T in [u8, u64, [u8; 15]]: max_variant_size = 16)]
+enum Either<T> {
+    Left(T),
+    Right(Option<T>),
+}
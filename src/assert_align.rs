@@ -8,22 +8,32 @@
 /// A `usize` has the same alignment as any pointer type:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_eq!(usize, *const u8, *mut u8);
 /// ```
 ///
 /// The following passes because `[i32; 4]` has the same alignment as `i32`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_eq!([i32; 4], i32);
 /// ```
 ///
+/// Generic types are checked via a function, just like with
+/// [`assert_size_eq!`](macro.assert_size_eq.html#generic-types):
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_align_eq!(fn slice_align for(T) [T; 4], T);
+///
+/// assert_instantiated!(slice_align::<u8>, slice_align::<u64>);
+/// ```
+///
 /// The following example fails to compile because `i32x4` explicitly has 4
 /// times the alignment as `[i32; 4]`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// # #[allow(non_camel_case_types)]
 /// #[repr(align(16))]
 /// struct i32x4([i32; 4]);
@@ -34,6 +44,10 @@
 /// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
 #[macro_export(local_inner_macros)]
 macro_rules! assert_align_eq {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(align_of each ==;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($y:ty),+ $(,)?) => {
         const _: fn() = || {
             use $crate::_core::mem::align_of;
@@ -64,7 +78,7 @@ macro_rules! assert_eq_align {
 /// A `u8` does not have the same alignment as a pointer:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_ne!(u8, *const u8);
 /// ```
 ///
@@ -72,11 +86,15 @@ macro_rules! assert_eq_align {
 /// alignment as a pointer:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_ne!(*const u8, usize);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! assert_align_ne {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(align_of each !=;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($y:ty),+ $(,)?) => {
         const _: fn() = || {
             use $crate::_core::mem::align_of;
@@ -93,7 +111,7 @@ macro_rules! assert_align_ne {
 /// a pointer:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_lt!(u8, u16, *const u8);
 /// ```
 ///
@@ -101,11 +119,15 @@ macro_rules! assert_align_ne {
 /// alignment as a pointer:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_lt!(*const u8, usize);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! assert_align_lt {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(align_of chain <;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($y:ty),+ $(,)?) => {
         const _: fn() = || {
             use $crate::_core::mem::align_of;
@@ -121,7 +143,7 @@ macro_rules! assert_align_lt {
 /// A `u8` and `i8` have smaller alignment than any pointer type:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_le!(u8, i8, *const u8);
 /// ```
 ///
@@ -129,11 +151,15 @@ macro_rules! assert_align_lt {
 /// alignment than `u8`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_le!(usize, u8);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! assert_align_le {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(align_of chain <=;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($y:ty),+ $(,)?) => {
         const _: fn() = || {
             use $crate::_core::mem::align_of;
@@ -150,7 +176,7 @@ macro_rules! assert_align_le {
 /// `u8`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_gt!(*const u8, u16, u8);
 /// ```
 ///
@@ -158,11 +184,15 @@ macro_rules! assert_align_le {
 /// alignment as a pointer:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_gt!(*const u8, usize);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! assert_align_gt {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(align_of chain >;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($y:ty),+ $(,)?) => {
         const _: fn() = || {
             use $crate::_core::mem::align_of;
@@ -178,7 +208,7 @@ macro_rules! assert_align_gt {
 /// A pointer has greater alignment than `u8` and `i8`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_ge!(*const u8, u8, i8);
 /// ```
 ///
@@ -186,11 +216,15 @@ macro_rules! assert_align_gt {
 /// than `usize`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_align_ge!(u8, usize);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! assert_align_ge {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(align_of chain >=;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($y:ty),+ $(,)?) => {
         const _: fn() = || {
             use $crate::_core::mem::align_of;
@@ -198,3 +232,33 @@ macro_rules! assert_align_ge {
         };
     };
 }
+
+/// Asserts that a type's alignment compares to a constant.
+///
+/// This takes the same forms as [`assert_size!`](macro.assert_size.html): a
+/// comparison like `<type> >= 8`, or a range like `<type> in 4..=16`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(align(64))]
+/// struct CacheLine([u8; 64]);
+///
+/// assert_align!(CacheLine == 64);
+/// assert_align!(u64 in 4..=8);
+/// ```
+///
+/// The following example fails to compile because byte arrays are only
+/// 1-byte aligned, which is shown in the error as the size of an array:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_align!([u8; 8] >= 8);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_align {
+    ($($tokens:tt)+) => {
+        _assert_layout!(align_of: $($tokens)+);
+    };
+}
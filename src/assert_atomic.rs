@@ -0,0 +1,170 @@
+/// Asserts that types have the same size and alignment as atomic types, and
+/// that the target supports those atomics.
+///
+/// This is needed for transmuting values to and from atomics, such as in an
+/// `AtomicCell<T>` that stores `T` in an `AtomicU64`. Atomic types are named
+/// without a path, and may be any of the integer atomics or `AtomicBool`.
+///
+/// On targets lacking atomics of the required width, such as `thumbv6m`,
+/// compilation fails with an error naming the width, as with
+/// [`assert_lock_free!`]. Otherwise, this checks the same as
+/// [`assert_transparent!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[derive(Clone, Copy)]
+/// #[repr(C, align(8))]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_atomic_compatible!(Point => AtomicU64, i8 => AtomicU8);
+/// assert_atomic_compatible!(bool => AtomicBool, usize => AtomicUsize);
+/// ```
+///
+/// The following example fails to compile because `[u8; 8]` is only aligned
+/// to 1 byte:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_atomic_compatible!([u8; 8] => AtomicU64);
+/// ```
+///
+/// [`assert_lock_free!`]: macro.assert_lock_free.html
+/// [`assert_transparent!`]: macro.assert_transparent.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_atomic_compatible {
+    (@atomic ($t:ty) AtomicBool) => {
+        assert_atomic_compatible!(@width ($t) AtomicBool "8" "8-bit");
+    };
+    (@atomic ($t:ty) AtomicU8) => {
+        assert_atomic_compatible!(@width ($t) AtomicU8 "8" "8-bit");
+    };
+    (@atomic ($t:ty) AtomicI8) => {
+        assert_atomic_compatible!(@width ($t) AtomicI8 "8" "8-bit");
+    };
+    (@atomic ($t:ty) AtomicU16) => {
+        assert_atomic_compatible!(@width ($t) AtomicU16 "16" "16-bit");
+    };
+    (@atomic ($t:ty) AtomicI16) => {
+        assert_atomic_compatible!(@width ($t) AtomicI16 "16" "16-bit");
+    };
+    (@atomic ($t:ty) AtomicU32) => {
+        assert_atomic_compatible!(@width ($t) AtomicU32 "32" "32-bit");
+    };
+    (@atomic ($t:ty) AtomicI32) => {
+        assert_atomic_compatible!(@width ($t) AtomicI32 "32" "32-bit");
+    };
+    (@atomic ($t:ty) AtomicU64) => {
+        assert_atomic_compatible!(@width ($t) AtomicU64 "64" "64-bit");
+    };
+    (@atomic ($t:ty) AtomicI64) => {
+        assert_atomic_compatible!(@width ($t) AtomicI64 "64" "64-bit");
+    };
+    (@atomic ($t:ty) AtomicUsize) => {
+        assert_atomic_compatible!(@width ($t) AtomicUsize "ptr" "pointer-sized");
+    };
+    (@atomic ($t:ty) AtomicIsize) => {
+        assert_atomic_compatible!(@width ($t) AtomicIsize "ptr" "pointer-sized");
+    };
+    (@atomic ($t:ty) $other:tt) => {
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "expected an integer atomic or `AtomicBool`, found `",
+            $crate::_core::stringify!($other), "`"
+        ));
+    };
+    (@width ($t:ty) $atomic:ident $width:tt $desc:tt) => {
+        assert_lock_free!(@width $atomic $width $desc);
+        // The atomic type only exists on targets that support it.
+        #[cfg(target_has_atomic = $width)]
+        assert_transparent!($t => $crate::_core::sync::atomic::$atomic);
+    };
+
+    ($($t:ty => $atomic:tt),+ $(,)?) => {
+        $(assert_atomic_compatible!(@atomic ($t) $atomic);)+
+    };
+}
+
+/// Asserts that the target has native, lock-free atomics for the given types.
+///
+/// Each type maps to a [`target_has_atomic`] width, with `ptr` standing for
+/// pointer-sized atomics. On targets lacking any of them, such as `thumbv6m`,
+/// which has no atomic read-modify-write operations, compilation fails with an
+/// error naming the missing width.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_lock_free!(u64, usize, ptr);
+/// assert_lock_free!(bool, u8, i32);
+/// ```
+///
+/// The following example fails to compile because 128-bit atomics aren't
+/// stable:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_lock_free!(u128);
+/// ```
+///
+/// [`target_has_atomic`]: https://doc.rust-lang.org/reference/conditional-compilation.html#target_has_atomic
+#[macro_export(local_inner_macros)]
+macro_rules! assert_lock_free {
+    (@type bool) => {
+        assert_lock_free!(@width bool "8" "8-bit");
+    };
+    (@type u8) => {
+        assert_lock_free!(@width u8 "8" "8-bit");
+    };
+    (@type i8) => {
+        assert_lock_free!(@width i8 "8" "8-bit");
+    };
+    (@type u16) => {
+        assert_lock_free!(@width u16 "16" "16-bit");
+    };
+    (@type i16) => {
+        assert_lock_free!(@width i16 "16" "16-bit");
+    };
+    (@type u32) => {
+        assert_lock_free!(@width u32 "32" "32-bit");
+    };
+    (@type i32) => {
+        assert_lock_free!(@width i32 "32" "32-bit");
+    };
+    (@type u64) => {
+        assert_lock_free!(@width u64 "64" "64-bit");
+    };
+    (@type i64) => {
+        assert_lock_free!(@width i64 "64" "64-bit");
+    };
+    (@type usize) => {
+        assert_lock_free!(@width usize "ptr" "pointer-sized");
+    };
+    (@type isize) => {
+        assert_lock_free!(@width isize "ptr" "pointer-sized");
+    };
+    (@type ptr) => {
+        assert_lock_free!(@width ptr "ptr" "pointer-sized");
+    };
+    (@type $other:tt) => {
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "no lock-free atomics for `", $crate::_core::stringify!($other),
+            "`, expected an integer type up to 64 bits, `bool`, or `ptr`"
+        ));
+    };
+    (@width $t:tt $width:tt $desc:tt) => {
+        #[cfg(not(target_has_atomic = $width))]
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "no lock-free atomics for `", $crate::_core::stringify!($t),
+            "`: this target lacks ", $desc, " atomics"
+        ));
+    };
+
+    ($($t:tt),+ $(,)?) => {
+        $(assert_lock_free!(@type $t);)+
+    };
+}
@@ -0,0 +1,95 @@
+/// Asserts that statics together take up at most the given number of bytes.
+///
+/// Unlike [`assert_size_eq_val!`], this works at the item level and compares
+/// against a budget rather than for equality. Statics are named by path, and
+/// their sizes are taken from their types without reading them, so `static mut`
+/// items can be listed too.
+///
+/// On failure, the error shows the total size as the size of an array next to
+/// the budget, and the overshoot as the size of another.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// static BUF_A: [u8; 1024] = [0; 1024];
+/// static BUF_B: [u8; 2048] = [0; 2048];
+/// static TABLE: [u32; 256] = [0; 256];
+///
+/// assert_static_budget!(4096; BUF_A, BUF_B, TABLE);
+/// ```
+///
+/// The following example fails to compile because the buffers take up 4100
+/// bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// static BUF_A: [u8; 2048] = [0; 2048];
+/// static BUF_B: [u8; 2052] = [0; 2052];
+///
+/// assert_static_budget!(4096; BUF_A, BUF_B);
+/// ```
+///
+/// [`assert_size_eq_val!`]: macro.assert_size_eq_val.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_static_budget {
+    ($budget:expr; $($s:path),+ $(,)?) => {
+        _assert_budget!(
+            (0 $(+ $crate::_util::size_of_static(
+                || $crate::_core::ptr::addr_of!($s)
+            ))+),
+            $budget,
+            $crate::_core::concat!(
+                "the statics ", $crate::_core::stringify!($($s),+),
+                " exceed the budget of ",
+                $crate::_core::stringify!($budget), " bytes"
+            )
+        );
+    };
+}
+
+/// Asserts that types together take up at most the given number of bytes.
+///
+/// This is useful for budgeting memory that's allocated elsewhere, such as on
+/// the stack or in a pool. As with
+/// [`assert_static_budget!`](macro.assert_static_budget.html), the error shows
+/// the total size and the overshoot on failure.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Task {
+///     stack: [u8; 96],
+///     state: u32,
+/// }
+///
+/// struct Scheduler {
+///     current: usize,
+///     ready: [bool; 8],
+/// }
+///
+/// assert_type_budget!(1024; [Task; 8], Scheduler);
+/// ```
+///
+/// The following example fails to compile because the types take up 16 bytes
+/// over the budget:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_type_budget!(32; [u64; 4], u128);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_type_budget {
+    ($budget:expr; $($t:ty),+ $(,)?) => {
+        _assert_budget!(
+            (0 $(+ $crate::_core::mem::size_of::<$t>())+),
+            $budget,
+            $crate::_core::concat!(
+                "the types ", $crate::_core::stringify!($($t),+),
+                " exceed the budget of ",
+                $crate::_core::stringify!($budget), " bytes"
+            )
+        );
+    };
+}
@@ -0,0 +1,161 @@
+/// Asserts that two fields of a type never share a cache line of the given
+/// size, to prevent false sharing between them.
+///
+/// Fields are compared by the bytes they span, which are computed with
+/// [`offset_of!`] and the sizes of their types. As with
+/// [`assert_offset_eq!`], fields of nested types can be reached with dotted
+/// paths like `inner.field`, and tuple fields by index.
+///
+/// Since the type may be placed at any address that's a multiple of its
+/// alignment, this holds for every such placement. For types aligned to the
+/// cache line, such as with `#[repr(align(64))]`, this means the fields start
+/// on different lines.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::sync::atomic::AtomicUsize;
+///
+/// #[repr(C, align(64))]
+/// struct Queue {
+///     head: AtomicUsize,
+///     _pad: [u8; 56],
+///     tail: AtomicUsize,
+/// }
+///
+/// assert_cache_separated!(Queue: head, tail; line = 64);
+/// ```
+///
+/// Without a large enough alignment, fields need a full line between them:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Counters {
+///     reads: u64,
+///     _pad: [u8; 64],
+///     writes: u64,
+/// }
+///
+/// assert_cache_separated!(Counters: reads, writes; line = 64);
+/// ```
+///
+/// The following example fails to compile because `head` and `tail` are
+/// adjacent:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C, align(64))]
+/// struct Queue {
+///     head: usize,
+///     tail: usize,
+/// }
+///
+/// assert_cache_separated!(Queue: head, tail; line = 64);
+/// ```
+///
+/// Lines must be at least one byte, so the following example fails to compile:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Pair(u64, u64);
+///
+/// assert_cache_separated!(Pair: 0, 1; line = 0);
+/// ```
+///
+/// [`offset_of!`]: https://doc.rust-lang.org/core/mem/macro.offset_of.html
+/// [`assert_offset_eq!`]: macro.assert_offset_eq.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_cache_separated {
+    ($t:ty: $($a:tt).+, $($b:tt).+; line = $line:expr $(,)?) => {
+        #[allow(unknown_lints, unsafe_code)]
+        const _: () = {
+            $crate::_core::assert!(
+                $line > 0,
+                "cache lines must be at least one byte",
+            );
+            $crate::_core::assert!(
+                !$crate::_util::share_line(
+                    $crate::_core::mem::offset_of!($t, $($a).+),
+                    $crate::_util::field_size(|x: *const $t| unsafe {
+                        $crate::_core::ptr::addr_of!((*x).$($a).+)
+                    }),
+                    $crate::_core::mem::offset_of!($t, $($b).+),
+                    $crate::_util::field_size(|x: *const $t| unsafe {
+                        $crate::_core::ptr::addr_of!((*x).$($b).+)
+                    }),
+                    $crate::_core::mem::align_of::<$t>(),
+                    $line,
+                ),
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($t), ".",
+                    $crate::_core::stringify!($($a).+), "` and `",
+                    $crate::_core::stringify!($t), ".",
+                    $crate::_core::stringify!($($b).+),
+                    "` may share a cache line"
+                ),
+            );
+        };
+    };
+}
+
+/// Asserts that a type is aligned to a cache line of the given size and
+/// spans a whole number of lines.
+///
+/// This combines [`assert_align!`] with a check that the size is a multiple
+/// of the line size, so that values in an array never share a line.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(align(128))]
+/// struct Slot {
+///     value: u64,
+/// }
+///
+/// assert_cache_aligned!(Slot, 128);
+/// assert_cache_aligned!([Slot; 4], 64);
+/// ```
+///
+/// The following example fails to compile because `Counter` is only aligned
+/// to 8 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Counter(u64);
+///
+/// assert_cache_aligned!(Counter, 64);
+/// ```
+///
+/// [`assert_align!`]: macro.assert_align.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_cache_aligned {
+    ($t:ty, $line:expr $(,)?) => {
+        _assert_layout!(@check [$crate::_core::mem::align_of] ($t) >= ($line));
+        const _: () = {
+            $crate::_core::assert!(
+                $line > 0,
+                "cache lines must be at least one byte",
+            );
+            $crate::_core::assert!(
+                $crate::_core::mem::size_of::<$t>()
+                    == $crate::_util::align_down(
+                        $crate::_core::mem::size_of::<$t>(),
+                        $line,
+                    ),
+                "{}",
+                $crate::_core::concat!(
+                    "the size of `", $crate::_core::stringify!($t),
+                    "` is not a multiple of the cache line"
+                ),
+            );
+        };
+        const _: [();
+            $crate::_util::align_down($crate::_core::mem::size_of::<$t>(), $line)
+        ] = [(); $crate::_core::mem::size_of::<$t>()];
+    };
+}
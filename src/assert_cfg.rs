@@ -5,7 +5,7 @@
 /// A project will simply fail to compile if the given configuration is not set.
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// // We're not masochists
 /// # #[cfg(not(target_pointer_width = "16"))] // Just in case
 /// assert_cfg!(not(target_pointer_width = "16"));
@@ -15,12 +15,12 @@
 /// report why. There is the option of providing a compile error message string:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// # #[cfg(any(unix, windows))]
 /// assert_cfg!(any(unix, windows), "There is only support for Unix or Windows");
 ///
 /// // User needs to specify a database back-end
-/// # #[cfg(target_pointer_width = "0")] // Impossible
+/// # #[cfg(any())] // Impossible
 /// assert_cfg!(all(not(all(feature = "mysql", feature = "mongodb")),
 ///                 any(    feature = "mysql", feature = "mongodb")),
 ///             "Must exclusively use MySQL or MongoDB as database back-end");
@@ -30,7 +30,7 @@
 /// both macOS _and_ Windows simultaneously:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_cfg!(all(target_os = "macos",
 ///                 target_os = "windows"),
 ///             "No, that's not how it works! ಠ_ಠ");
@@ -0,0 +1,184 @@
+/// Asserts that types have no drop glue, so that skipping their destructors
+/// leaks nothing.
+///
+/// This is checked with [`needs_drop`], and is what makes it sound for arenas
+/// and bump allocators to free values without dropping them. Types with no
+/// drop glue neither implement `Drop` nor contain anything that does.
+///
+/// # Syntax
+///
+/// ```skip
+/// assert_no_drop_glue!(<type>, ...);
+/// assert_no_drop_glue!(fn <name> for(<generics>) <type>, ...);
+/// assert_no_drop_glue!(for(<generics>) <type>, ...);
+/// ```
+///
+/// These forms work the same as those of [`assert_zst!`]. The unnamed
+/// `for(...)` form proves that the types have no drop glue for every choice of
+/// generics, which requires them to be sized.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Node<'a> {
+///     value: u64,
+///     next: Option<&'a Node<'a>>,
+/// }
+///
+/// struct Pair<T>(T, T);
+///
+/// assert_no_drop_glue!(Node, [u8; 16], &str);
+/// assert_no_drop_glue!(for(T: Copy) Pair<T>);
+/// assert_no_drop_glue!(fn no_drop_glue for(T) T);
+///
+/// fn alloc<T>(value: T) -> T {
+///     no_drop_glue::<T>();
+///     value
+/// }
+/// ```
+///
+/// The following example fails to compile because dropping `Node` frees its
+/// `String`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Node {
+///     value: u64,
+///     name: String,
+/// }
+///
+/// assert_no_drop_glue!(Node);
+/// ```
+///
+/// The following example fails to compile because `Pair<T>` only has no drop
+/// glue for some `T`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Pair<T>(T, T);
+///
+/// assert_no_drop_glue!(for(T) Pair<T>);
+/// ```
+///
+/// [`needs_drop`]: https://doc.rust-lang.org/core/mem/fn.needs_drop.html
+/// [`assert_zst!`]: macro.assert_zst.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_no_drop_glue {
+    (@check $($t:ty),+) => {
+        $(
+            const {
+                $crate::_core::assert!(
+                    !$crate::_core::mem::needs_drop::<$t>(),
+                    "{}",
+                    $crate::_core::concat!(
+                        "`", $crate::_core::stringify!($t), "` has drop glue"
+                    ),
+                )
+            };
+        )+
+    };
+    ($vis:vis fn $name:ident for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            assert_no_drop_glue!(@check $($t),+);
+        }
+    };
+    (for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        const _: () = {
+            // The arguments are dropped when this returns, which a `const fn`
+            // may only do if they're known to have no drop glue.
+            #[allow(dead_code)]
+            const fn assert_no_drop_glue<$($generic)*>($(_: $t),+) {}
+        };
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                !$crate::_core::mem::needs_drop::<$t>(),
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($t), "` has drop glue"
+                ),
+            );
+        )+
+    };
+}
+
+/// Asserts that types need to be dropped.
+///
+/// This takes the same forms as
+/// [`assert_no_drop_glue!`](macro.assert_no_drop_glue.html), and is useful for
+/// ensuring that types owning resources keep running their destructors.
+/// However, drop glue can't be proven for every choice of generics, so generic
+/// types can only be checked with the `fn <name> for(...)` form.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Guard;
+///
+/// impl Drop for Guard {
+///     fn drop(&mut self) {}
+/// }
+///
+/// assert_needs_drop!(Guard, String, Vec<u8>, Option<Guard>);
+/// assert_needs_drop!(fn boxed_needs_drop for(T) Box<T>);
+/// ```
+///
+/// The following example fails to compile because `u32` has no drop glue:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_needs_drop!(u32);
+/// ```
+///
+/// The following example fails to compile because the unnamed `for(...)` form
+/// isn't supported:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_needs_drop!(for(T) Box<T>);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_needs_drop {
+    (@check $($t:ty),+) => {
+        $(
+            const {
+                $crate::_core::assert!(
+                    $crate::_core::mem::needs_drop::<$t>(),
+                    "{}",
+                    $crate::_core::concat!(
+                        "`", $crate::_core::stringify!($t),
+                        "` doesn't need to be dropped"
+                    ),
+                )
+            };
+        )+
+    };
+    ($vis:vis fn $name:ident for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            assert_needs_drop!(@check $($t),+);
+        }
+    };
+    (for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        $crate::_core::compile_error!(
+            "drop glue can't be proven for every choice of generics, so use \
+             `fn <name> for(...)` instead"
+        );
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                $crate::_core::mem::needs_drop::<$t>(),
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($t),
+                    "` doesn't need to be dropped"
+                ),
+            );
+        )+
+    };
+}
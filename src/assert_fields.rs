@@ -6,7 +6,7 @@
 /// result of `#[cfg]`. This can be an issue when exposing a public API.
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions;
+/// # #[macro_use] extern crate static_assertions_next;
 /// pub struct Ty {
 ///     #[cfg(windows)]
 ///     pub val1: u8,
@@ -26,7 +26,7 @@
 /// This macro even works with `enum` variants:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// enum Data {
 ///     Val {
 ///         id: i32,
@@ -42,7 +42,7 @@
 /// The following example fails to compile because [`Range`] does not have a field named `middle`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// use std::ops::Range;
 ///
 /// assert_fields!(Range<u32>: middle);
@@ -12,7 +12,7 @@
 /// or `Pop`:
 ///
 /// ```compile_fail
-/// # use static_assertions::assert_impl_one; fn main() {}
+/// # use static_assertions_next::assert_impl_one; fn main() {}
 /// struct Foo;
 ///
 /// trait Snap {}
@@ -25,7 +25,7 @@
 /// If _only_ `Crackle` is implemented, the assertion passes:
 ///
 /// ```
-/// # use static_assertions::assert_impl_one; fn main() {}
+/// # use static_assertions_next::assert_impl_one; fn main() {}
 /// # struct Foo;
 /// # trait Snap {}
 /// # trait Crackle {}
@@ -38,7 +38,7 @@
 /// If `Snap` or `Pop` is _also_ implemented, the assertion fails:
 ///
 /// ```compile_fail
-/// # use static_assertions::assert_impl_one; fn main() {}
+/// # use static_assertions_next::assert_impl_one; fn main() {}
 /// # struct Foo;
 /// # trait Snap {}
 /// # trait Crackle {}
@@ -92,7 +92,7 @@ macro_rules! assert_impl_one {
 /// [`Sync`], as well as traits with [blanket `impl`s][blanket].
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_all!(u32: Copy, Send);
 /// assert_impl_all!(&str: Into<String>);
 /// ```
@@ -101,7 +101,7 @@ macro_rules! assert_impl_one {
 /// [`Send`] since they cannot be moved between threads safely:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_all!(*const u8: Send);
 /// ```
 ///
@@ -125,7 +125,7 @@ macro_rules! assert_impl_all {
 /// `u8` cannot be converted from `u16`, but it can be converted into `u16`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_any!(u8: From<u16>, Into<u16>);
 /// ```
 ///
@@ -133,7 +133,7 @@ macro_rules! assert_impl_all {
 /// [`Send`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_any!((): From<u8>, From<u16>, Send);
 /// ```
 ///
@@ -142,7 +142,7 @@ macro_rules! assert_impl_all {
 /// safely:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_any!(*const u8: Send, Sync);
 /// ```
 ///
@@ -170,7 +170,7 @@ macro_rules! assert_impl_any {
 /// Although `u32` implements `From<u16>`, it does not implement `Into<usize>`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_not_all!(u32: From<u16>, Into<usize>);
 /// ```
 ///
@@ -178,14 +178,14 @@ macro_rules! assert_impl_any {
 /// `u64`.
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_not_all!(u32: Into<u64>);
 /// ```
 ///
 /// The following compiles because [`Cell`] is not both [`Sync`] _and_ [`Send`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// use std::cell::Cell;
 ///
 /// assert_impl_not_all!(Cell<u32>: Sync, Send);
@@ -194,8 +194,8 @@ macro_rules! assert_impl_any {
 /// But it is [`Send`], so this fails to compile:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
-/// # std::cell::Cell;
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// # use std::cell::Cell;
 /// assert_impl_not_all!(Cell<u32>: Send);
 /// ```
 ///
@@ -242,14 +242,14 @@ macro_rules! assert_not_impl_all {
 /// the following would fail to compile:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_not_any!(u32: Into<usize>, Into<u8>);
 /// ```
 ///
 /// This is also good for simple one-off cases:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_not_any!(&'static mut u8: Copy);
 /// ```
 ///
@@ -257,7 +257,7 @@ macro_rules! assert_not_impl_all {
 /// `u64` even though it can not be converted into a `u16`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl_not_any!(u32: Into<u64>, Into<u16>);
 /// ```
 ///
@@ -325,14 +325,14 @@ macro_rules! assert_not_impl_any {
 /// the following would fail to compile:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl!(u32: !((Into<usize>) & (Into<u8>)));
 /// ```
 ///
 /// Check that a type is [`Send`] but not [`Sync`].
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// use std::cell::Cell;
 ///
 /// assert_impl!(Cell<u32>: Send & !Sync);
@@ -341,14 +341,14 @@ macro_rules! assert_not_impl_any {
 /// Check simple one-off cases:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl!(&'static mut u8: !Copy);
 /// ```
 ///
 /// Check that a type is _always_ [`Clone`] even when its parameter isn't:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// use std::rc::Rc;
 ///
 /// assert_impl!(for(T) Rc<T>: Clone);
@@ -358,7 +358,7 @@ macro_rules! assert_not_impl_any {
 /// either `u32` or `u16`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl!(u64: (Into<u32>) | (Into<u16>));
 /// ```
 ///
@@ -0,0 +1,44 @@
+/// Instantiates generic assertion functions with concrete types.
+///
+/// Assertions over generic types, such as
+/// [`assert_size_eq!(fn name for(T) ...)`](macro.assert_size_eq.html), are
+/// defined as functions whose checks run when they're instantiated. This macro
+/// registers instantiations at the item level, while generic code can simply
+/// call the function, e.g. `name::<T>()`, to have the checks fire for every
+/// type it's used with.
+///
+/// Note that these checks happen after type checking, so they're reported by
+/// `cargo build` and `cargo test` but not by `cargo check`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Wrapper<T>(T);
+///
+/// assert_size_eq!(fn wrapper_size for(T) Wrapper<T>, T);
+///
+/// assert_instantiated!(wrapper_size::<u8>, wrapper_size::<String>);
+/// ```
+///
+/// The following example fails to compile because `Tagged<u8>` is twice as
+/// large as `u8`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Tagged<T>(T, bool);
+///
+/// assert_size_eq!(fn tagged_size for(T) Tagged<T>, T);
+///
+/// assert_instantiated!(tagged_size::<u8>);
+/// ```
+#[macro_export]
+macro_rules! assert_instantiated {
+    ($($f:path),+ $(,)?) => {
+        $(const _: () = {
+            // Statics are always codegened, which instantiates `$f`.
+            #[used]
+            static INSTANCE: fn() = $f;
+        };)+
+    };
+}
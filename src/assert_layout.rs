@@ -0,0 +1,216 @@
+/// Asserts the size and alignment of a type depending on the target.
+///
+/// Each arm maps a [configuration predicate] to the expected `size` and/or
+/// `align` of the type. Arms are tried in order and only the first one that
+/// matches the current target is checked, similar to `match`. A final `_` arm
+/// can either give a fallback layout or be `compile_error`, optionally with a
+/// message.
+///
+/// Targets that aren't matched by any arm fail to compile, so that new targets
+/// get their layout reviewed rather than silently passing.
+///
+/// The expected values must be single tokens, such as literals or `const`
+/// items, or be wrapped in parentheses.
+///
+/// # Examples
+///
+/// A type containing pointers is larger on 64-bit targets:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Packet {
+///     data: *const u8,
+///     len: usize,
+///     id: u32,
+/// }
+///
+/// assert_layout_by_target!(Packet {
+///     target_pointer_width = "64" => size 24 align 8,
+///     target_pointer_width = "32" => size 12 align 4,
+///     _ => compile_error("`Packet` has only been laid out for 32/64-bit"),
+/// });
+/// ```
+///
+/// A fallback layout applies to every other target:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const WORD: usize = core::mem::size_of::<usize>();
+///
+/// assert_layout_by_target!((u8, *const u8) {
+///     target_pointer_width = "64" => size 16 align 8,
+///     _ => size (2 * WORD) align WORD,
+/// });
+/// ```
+///
+/// The following example fails to compile on 32-bit and 64-bit targets because
+/// no arm matches them:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_layout_by_target!(u32 {
+///     target_pointer_width = "16" => size 4,
+/// });
+/// ```
+///
+/// Every arm must expect something, so the following example fails to
+/// compile:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_layout_by_target!(u32 {
+///     target_pointer_width = "64" =>,
+///     _ => size 4,
+/// });
+/// ```
+///
+/// [configuration predicate]: https://doc.rust-lang.org/reference/conditional-compilation.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_layout_by_target {
+    ($t:ty { $($arms:tt)* }) => {
+        _assert_layout_by_target!(@arm $t [] $($arms)*);
+    };
+}
+
+/// Asserts that two types have the same layout: size, alignment, and the
+/// offsets and sizes of corresponding fields.
+///
+/// Fields are paired as `<field of A> => <field of B>`. As with
+/// [`assert_offset_eq!`], fields of nested types can be reached with dotted
+/// paths like `inner.field`, and tuple fields by index.
+///
+/// Each mismatch is reported separately, naming the field along with both
+/// values in an array length error, so that every difference shows up in a
+/// single build.
+///
+/// # Examples
+///
+/// This is useful for keeping a vendored copy of a `#[repr(C)]` type in sync
+/// with the original:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+///     flags: u16,
+/// }
+///
+/// mod vendored {
+///     #[repr(C)]
+///     pub struct Header {
+///         pub signature: u32,
+///         pub size: u16,
+///         pub flags: u16,
+///     }
+/// }
+///
+/// assert_layout_eq!(Header, vendored::Header;
+///     magic => signature,
+///     len => size,
+///     flags => flags,
+/// );
+/// ```
+///
+/// Nested fields can be compared against flattened ones:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[repr(C)]
+/// struct Line {
+///     start: Point,
+///     end: Point,
+/// }
+///
+/// #[repr(C)]
+/// struct Segment {
+///     x0: f32,
+///     y0: f32,
+///     x1: f32,
+///     y1: f32,
+/// }
+///
+/// assert_layout_eq!(Line, [f32; 4]);
+/// assert_layout_eq!(Line, Segment; start.y => y0, end.x => x1, end.y => y1);
+/// ```
+///
+/// The following example fails to compile because `len` is 2 bytes larger in
+/// `Theirs`, which also shifts `flags` by 2 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Ours {
+///     len: u16,
+///     flags: u16,
+/// }
+///
+/// #[repr(C)]
+/// struct Theirs {
+///     len: u32,
+///     flags: u16,
+/// }
+///
+/// assert_layout_eq!(Ours, Theirs; len => len, flags => flags);
+/// ```
+///
+/// [`assert_offset_eq!`]: macro.assert_offset_eq.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_layout_eq {
+    (@eq $x:expr, $y:expr, $($msg:expr),+) => {
+        const _: () = $crate::_core::assert!(
+            $x == $y,
+            "{}",
+            $crate::_core::concat!($($msg),+),
+        );
+        const _: [(); $y] = [(); $x];
+    };
+    ($x:ty, $y:ty $(; $($($f:tt).+ => $($g:tt).+),* $(,)?)?) => {
+        #[allow(unknown_lints, unsafe_code)]
+        const _: () = {
+            assert_layout_eq!(@eq
+                $crate::_core::mem::size_of::<$x>(),
+                $crate::_core::mem::size_of::<$y>(),
+                "`", $crate::_core::stringify!($x), "` and `",
+                $crate::_core::stringify!($y), "` have different sizes"
+            );
+            assert_layout_eq!(@eq
+                $crate::_core::mem::align_of::<$x>(),
+                $crate::_core::mem::align_of::<$y>(),
+                "`", $crate::_core::stringify!($x), "` and `",
+                $crate::_core::stringify!($y), "` have different alignments"
+            );
+            $($(
+                assert_layout_eq!(@eq
+                    $crate::_core::mem::offset_of!($x, $($f).+),
+                    $crate::_core::mem::offset_of!($y, $($g).+),
+                    "`", $crate::_core::stringify!($x), ".",
+                    $crate::_core::stringify!($($f).+), "` and `",
+                    $crate::_core::stringify!($y), ".",
+                    $crate::_core::stringify!($($g).+),
+                    "` have different offsets"
+                );
+                assert_layout_eq!(@eq
+                    $crate::_util::field_size(|x: *const $x| unsafe {
+                        $crate::_core::ptr::addr_of!((*x).$($f).+)
+                    }),
+                    $crate::_util::field_size(|y: *const $y| unsafe {
+                        $crate::_core::ptr::addr_of!((*y).$($g).+)
+                    }),
+                    "`", $crate::_core::stringify!($x), ".",
+                    $crate::_core::stringify!($($f).+), "` and `",
+                    $crate::_core::stringify!($y), ".",
+                    $crate::_core::stringify!($($g).+),
+                    "` have different sizes"
+                );
+            )*)?
+        };
+    };
+}
@@ -0,0 +1,90 @@
+/// Asserts that types have a [niche], so that wrapping them in an [`Option`]
+/// doesn't make them any larger.
+///
+/// This is equivalent to `assert_size_eq!(Option<T>, T)` for each type `T`.
+///
+/// # Examples
+///
+/// References, non-null pointers, and enums with spare discriminants are all
+/// niche-optimized:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::num::NonZeroU32;
+/// use std::ptr::NonNull;
+///
+/// struct Handle(NonZeroU32);
+///
+/// assert_niche!(Handle, NonNull<u8>, &str, Box<u64>, bool);
+/// ```
+///
+/// The following example fails to compile because every bit pattern of `u32`
+/// is a valid value:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Handle(u32);
+///
+/// assert_niche!(Handle);
+/// ```
+///
+/// [niche]: https://rust-lang.github.io/unsafe-code-guidelines/glossary.html#niche
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_niche {
+    ($($t:ty),+ $(,)?) => {
+        $(assert_size_eq!($crate::_core::option::Option<$t>, $t);)+
+    };
+}
+
+/// Asserts how many [niches] a type has, i.e. how many `Option`s or dataless
+/// enum variants it can absorb without growing in size.
+///
+/// This takes the same forms as [`assert_size!`](macro.assert_size.html), with
+/// the type's niche count in place of its size, and also reveals the count in
+/// error messages. Niches are only counted up to one more than the value that
+/// they're compared against, which can be at most 510, so larger counts are
+/// reported as that.
+///
+/// # Examples
+///
+/// A `bool` only uses 2 of its 256 bit patterns:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_niche_count!(bool == 254);
+/// assert_niche_count!(&u8 == 1);
+/// assert_niche_count!(Option<bool> >= 200);
+/// ```
+///
+/// This is useful for ensuring that nesting in `Option` stays free:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// enum State {
+///     Idle,
+///     Busy,
+///     Done,
+/// }
+///
+/// assert_niche_count!(State in 2..);
+///
+/// type Cached = Option<Option<State>>;
+/// # assert_size_eq!(Cached, State);
+/// ```
+///
+/// The following example fails to compile because `char` has far more
+/// niches, which is shown in the error as the size of an array:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_niche_count!(char < 8);
+/// ```
+///
+/// [niches]: https://rust-lang.github.io/unsafe-code-guidelines/glossary.html#niche
+#[macro_export(local_inner_macros)]
+macro_rules! assert_niche_count {
+    ($($tokens:tt)+) => {
+        _assert_layout!([bounded _niche_count]: $($tokens)+);
+    };
+}
@@ -0,0 +1,94 @@
+/// Asserts that a struct has no padding bytes, given all of its fields and
+/// their types.
+///
+/// This is useful for types that are copied byte for byte, such as onto the
+/// wire, where padding would leak uninitialized memory.
+///
+/// Every field of the struct must be listed, along with its type. The size of
+/// the struct is compared against the sum of the field sizes, and the offset
+/// at which each field ends is checked to be the start of another field or
+/// the end of the struct, so that errors point out where the padding is.
+///
+/// The `proc` feature's `#[assert(no_padding)]` attribute performs the same
+/// checks, but reads the fields from the struct's definition.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+///     flags: u16,
+/// }
+///
+/// #[repr(C)]
+/// struct Point(i32, i32);
+///
+/// assert_no_padding!(Header: magic: u32, len: u16, flags: u16);
+/// assert_no_padding!(Point: 0: i32, 1: i32);
+/// ```
+///
+/// The following example fails to compile because there are 2 bytes of
+/// padding after `len`, so that `crc` is aligned:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+///     crc: u32,
+/// }
+///
+/// assert_no_padding!(Header: magic: u32, len: u16, crc: u32);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_no_padding {
+    (@fields ($t:ty) $all:tt $($f:tt: $ft:ty),+) => {
+        $(assert_no_padding!(@field ($t) $all $f: $ft);)+
+
+        #[allow(unknown_lints, clippy::identity_op)]
+        const _: () = $crate::_core::assert!(
+            $crate::_core::mem::size_of::<$t>()
+                == 0 $(+ $crate::_core::mem::size_of::<$ft>())+,
+            "{}",
+            $crate::_core::concat!(
+                "`", $crate::_core::stringify!($t),
+                "` is larger than its fields, which must all be listed"
+            ),
+        );
+        #[allow(unknown_lints, clippy::identity_op)]
+        const _: [(); 0 $(+ $crate::_core::mem::size_of::<$ft>())+] =
+            [(); $crate::_core::mem::size_of::<$t>()];
+    };
+    (@field ($t:ty) [$($g:tt),+] $f:tt: $ft:ty) => {
+        // Ensures that the given type is that of the field, without moving
+        // it out of types that implement `Drop`.
+        #[allow(unknown_lints, unsafe_code)]
+        const _: fn(*const $t) -> *const $ft =
+            |x| unsafe { $crate::_core::ptr::addr_of!((*x).$f) };
+
+        // The field must end where another starts or the type ends.
+        const _: () = $crate::_core::assert!(
+            $crate::_core::mem::offset_of!($t, $f)
+                + $crate::_core::mem::size_of::<$ft>()
+                == $crate::_core::mem::size_of::<$t>()
+            $(
+                || $crate::_core::mem::offset_of!($t, $f)
+                    + $crate::_core::mem::size_of::<$ft>()
+                    == $crate::_core::mem::offset_of!($t, $g)
+            )+,
+            "{}",
+            $crate::_core::concat!(
+                "`", $crate::_core::stringify!($t),
+                "` has padding after `",
+                $crate::_core::stringify!($f), "`"
+            ),
+        );
+    };
+    ($t:ty: $($f:tt: $ft:ty),+ $(,)?) => {
+        assert_no_padding!(@fields ($t) [$($f),+] $($f: $ft),+);
+    };
+}
@@ -14,7 +14,7 @@
 /// use dynamic dispatch can still do so in future compatible crate versions.
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// trait MySafeTrait {
 ///     fn foo(&self) -> u32;
 /// }
@@ -25,7 +25,7 @@
 /// Works with traits that are not in the calling module:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// mod inner {
 ///     pub trait BasicTrait {
 ///         fn bar(&self);
@@ -40,7 +40,7 @@
 ///  between threads safely:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_impl!(*const u8, Send);
 /// ```
 ///
@@ -48,7 +48,7 @@
 /// `where Self: Sized` are not allowed in [object-safe][object] trait methods:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// trait MyUnsafeTrait {
 ///     fn baz<T>(&self) -> T;
 /// }
@@ -59,7 +59,7 @@
 /// When we fix that, the previous code will compile:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// trait MyUnsafeTrait {
 ///     fn baz<T>(&self) -> T where Self: Sized;
 /// }
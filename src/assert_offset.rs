@@ -0,0 +1,225 @@
+/// Asserts that the type's fields are at the given byte offsets.
+///
+/// Offsets are computed with [`offset_of!`], so fields of nested structs can
+/// be reached with dotted paths like `inner.field`, and tuple fields by index.
+///
+/// # Examples
+///
+/// This is useful for checking that the layout of a `#[repr(C)]` type matches
+/// a wire format or some foreign type:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+///     flags: u16,
+/// }
+///
+/// assert_offset_eq!(Header: magic => 0, len => 4, flags => 8);
+/// ```
+///
+/// Fields of nested types are reached via dotted paths:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// # #[repr(C)] struct Header { magic: u32, len: u32, flags: u16 }
+/// #[repr(C)]
+/// struct Packet {
+///     header: Header,
+///     body: [u8; 4],
+/// }
+///
+/// assert_offset_eq!(Packet: header.flags => 8, body => 12);
+/// ```
+///
+/// The following example fails to compile because `len` comes after `magic`.
+/// The actual offset of 4 is shown in the error as the size of an array:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+/// }
+///
+/// assert_offset_eq!(Header: len => 0);
+/// ```
+///
+/// [`offset_of!`]: https://doc.rust-lang.org/core/mem/macro.offset_of.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_offset_eq {
+    ($t:ty: $($($f:tt).+ => $offset:expr),+ $(,)?) => {
+        const _: fn() = || {
+            $(const_assert_eq_usize!(
+                $offset,
+                $crate::_core::mem::offset_of!($t, $($f).+),
+            );)+
+        };
+    };
+}
+
+/// Asserts that the type's fields are at byte offsets less than the given
+/// values.
+///
+/// # Examples
+///
+/// Frequently accessed fields can be kept within the first cache line:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Queue {
+///     head: usize,
+///     tail: usize,
+///     buf: [u8; 256],
+/// }
+///
+/// assert_offset_lt!(Queue: head => 64, tail => 64);
+/// ```
+///
+/// The following example fails to compile because `buf` starts after the
+/// first 64 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Queue {
+///     buf: [u8; 256],
+///     head: usize,
+/// }
+///
+/// assert_offset_lt!(Queue: head => 64);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_offset_lt {
+    ($t:ty: $($($f:tt).+ => $offset:expr),+ $(,)?) => {
+        const _: fn() = || {
+            $(const_assert_lt_usize!(
+                $crate::_core::mem::offset_of!($t, $($f).+),
+                $offset,
+            );)+
+        };
+    };
+}
+
+/// Asserts that the type's fields are at byte offsets less than or equal to
+/// the given values.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Pair(u8, u32);
+///
+/// assert_offset_le!(Pair: 0 => 0, 1 => 4);
+/// ```
+///
+/// The following example fails to compile because `u32` fields are 4-byte
+/// aligned:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Pair(u8, u32);
+///
+/// assert_offset_le!(Pair: 1 => 1);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_offset_le {
+    ($t:ty: $($($f:tt).+ => $offset:expr),+ $(,)?) => {
+        const _: fn() = || {
+            $(const_assert_le_usize!(
+                $crate::_core::mem::offset_of!($t, $($f).+),
+                $offset,
+            );)+
+        };
+    };
+}
+
+/// Asserts that the type's fields are at byte offsets greater than the given
+/// values.
+///
+/// # Examples
+///
+/// Fields written by different threads can be kept on separate cache lines:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Channel {
+///     head: usize,
+///     _pad: [u8; 64],
+///     tail: usize,
+/// }
+///
+/// assert_offset_gt!(Channel: tail => 63);
+/// ```
+///
+/// The following example fails to compile because `tail` directly follows
+/// `head`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Channel {
+///     head: usize,
+///     tail: usize,
+/// }
+///
+/// assert_offset_gt!(Channel: tail => 63);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_offset_gt {
+    ($t:ty: $($($f:tt).+ => $offset:expr),+ $(,)?) => {
+        const _: fn() = || {
+            $(const_assert_gt_usize!(
+                $crate::_core::mem::offset_of!($t, $($f).+),
+                $offset,
+            );)+
+        };
+    };
+}
+
+/// Asserts that the type's fields are at byte offsets greater than or equal to
+/// the given values.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Frame {
+///     header: [u8; 16],
+///     payload: [u8; 64],
+/// }
+///
+/// assert_offset_ge!(Frame: payload => 16);
+/// ```
+///
+/// The following example fails to compile because `payload` starts at 16:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C)]
+/// struct Frame {
+///     header: [u8; 16],
+///     payload: [u8; 64],
+/// }
+///
+/// assert_offset_ge!(Frame: payload => 32);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_offset_ge {
+    ($t:ty: $($($f:tt).+ => $offset:expr),+ $(,)?) => {
+        const _: fn() = || {
+            $(const_assert_ge_usize!(
+                $crate::_core::mem::offset_of!($t, $($f).+),
+                $offset,
+            );)+
+        };
+    };
+}
@@ -0,0 +1,71 @@
+/// Asserts that the fields of a struct are at offsets that are multiples of
+/// their types' alignments, given the fields and their types.
+///
+/// This is mainly useful for `#[repr(packed)]` structs, whose fields may
+/// otherwise be misaligned. The `proc` feature's `#[assert(naturally_aligned)]`
+/// attribute performs the same checks, but reads the fields from the struct's
+/// definition.
+///
+/// Note that a packed struct itself has an alignment of 1, so its fields are
+/// only aligned in memory when the struct is placed at a suitably aligned
+/// address, such as within a `#[repr(align(N))]` wrapper.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C, packed)]
+/// struct Frame {
+///     kind: u8,
+///     flags: u8,
+///     len: u16,
+///     crc: u32,
+/// }
+///
+/// assert_packed_aligned!(Frame: kind: u8, flags: u8, len: u16, crc: u32);
+/// ```
+///
+/// The following example fails to compile because `len` is at offset 1. The
+/// offset is shown in the error as the size of an array, along with the
+/// aligned offset before it:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C, packed)]
+/// struct Frame {
+///     kind: u8,
+///     len: u16,
+/// }
+///
+/// assert_packed_aligned!(Frame: kind: u8, len: u16);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_packed_aligned {
+    ($t:ty: $($f:tt: $ft:ty),+ $(,)?) => {
+        $(
+            #[allow(unknown_lints, unsafe_code)]
+            const _: fn(*const $t) -> *const $ft =
+                |x| unsafe { $crate::_core::ptr::addr_of!((*x).$f) };
+
+            const _: () = $crate::_core::assert!(
+                $crate::_core::mem::offset_of!($t, $f)
+                    == $crate::_util::align_down(
+                        $crate::_core::mem::offset_of!($t, $f),
+                        $crate::_core::mem::align_of::<$ft>(),
+                    ),
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($t), ".",
+                    $crate::_core::stringify!($f), "` is not aligned to `",
+                    $crate::_core::stringify!($ft), "`"
+                ),
+            );
+            const _: [();
+                $crate::_util::align_down(
+                    $crate::_core::mem::offset_of!($t, $f),
+                    $crate::_core::mem::align_of::<$ft>(),
+                )
+            ] = [(); $crate::_core::mem::offset_of!($t, $f)];
+        )+
+    };
+}
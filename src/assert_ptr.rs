@@ -0,0 +1,281 @@
+/// Asserts that pointers to types are thin, being the size of a `usize`.
+///
+/// This is the case for sized types, whose pointers carry no metadata, and
+/// code that stores `*const T` as an address, such as in an [`AtomicPtr`], may
+/// depend on it. On failure, the error names the type and shows the pointer's
+/// actual size as the size of an array.
+///
+/// # Syntax
+///
+/// ```skip
+/// assert_thin_ptr!(<type>, ...);
+/// assert_thin_ptr!(fn <name> for(<generics>) <type>, ...);
+/// assert_thin_ptr!(for(<generics>) <type>, ...);
+/// ```
+///
+/// These forms work the same as those of [`assert_zst!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::sync::atomic::{AtomicPtr, Ordering};
+///
+/// assert_thin_ptr!(u8, [u32; 4], String, &[u8]);
+///
+/// assert_thin_ptr!(for(T) Box<T>, Option<&T>);
+/// assert_thin_ptr!(fn thin for(T: ?Sized) T);
+///
+/// fn store<T: ?Sized>(slot: &AtomicPtr<()>, ptr: *const T) {
+///     thin::<T>();
+///     slot.store(ptr as *mut (), Ordering::Release);
+/// }
+/// ```
+///
+/// The following example fails to compile because pointers to slices also
+/// hold their length:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_thin_ptr!([u8]);
+/// ```
+///
+/// The following example fails to compile because pointers to `T` are only
+/// thin for some `T`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_thin_ptr!(for(T: ?Sized) T);
+/// ```
+///
+/// [`AtomicPtr`]: https://doc.rust-lang.org/core/sync/atomic/struct.AtomicPtr.html
+/// [`assert_zst!`]: macro.assert_zst.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_thin_ptr {
+    (@check $($t:ty),+) => {
+        $(
+            const {
+                $crate::_core::assert!(
+                    $crate::_core::mem::size_of::<*const $t>()
+                        == $crate::_core::mem::size_of::<usize>(),
+                    "{}",
+                    $crate::_core::concat!(
+                        "pointers to `", $crate::_core::stringify!($t),
+                        "` are not thin"
+                    ),
+                )
+            };
+        )+
+    };
+    ($vis:vis fn $name:ident for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            assert_thin_ptr!(@check $($t),+);
+        }
+    };
+    (for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_thin_ptr<$($generic)*>() {
+                $(let _ = $crate::_core::mem::transmute::<*const $t, usize>;)+
+            }
+        };
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                $crate::_core::mem::size_of::<*const $t>()
+                    == $crate::_core::mem::size_of::<usize>(),
+                "{}",
+                $crate::_core::concat!(
+                    "pointers to `", $crate::_core::stringify!($t),
+                    "` are not thin"
+                ),
+            );
+            const _: [(); $crate::_core::mem::size_of::<usize>()] =
+                [(); $crate::_core::mem::size_of::<*const $t>()];
+        )+
+    };
+}
+
+/// Asserts that pointers to types are fat, carrying metadata alongside the
+/// address.
+///
+/// This is the case for dynamically sized types, such as slices, `str`, and
+/// trait objects. It takes the same forms as
+/// [`assert_thin_ptr!`](macro.assert_thin_ptr.html).
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::fmt::Debug;
+///
+/// struct Packet {
+///     len: u16,
+///     data: [u8],
+/// }
+///
+/// assert_fat_ptr!([u8], str, dyn Debug, Packet);
+/// assert_fat_ptr!(for(T) [T], [[T; 2]]);
+/// ```
+///
+/// The following example fails to compile because `u64` is sized:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_fat_ptr!(u64);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_fat_ptr {
+    (@check $($t:ty),+) => {
+        $(
+            const {
+                $crate::_core::assert!(
+                    $crate::_core::mem::size_of::<*const $t>()
+                        > $crate::_core::mem::size_of::<usize>(),
+                    "{}",
+                    $crate::_core::concat!(
+                        "pointers to `", $crate::_core::stringify!($t),
+                        "` are not fat"
+                    ),
+                )
+            };
+        )+
+    };
+    ($vis:vis fn $name:ident for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            assert_fat_ptr!(@check $($t),+);
+        }
+    };
+    (for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fat_ptr<$($generic)*>() {
+                $(let _ = $crate::_core::mem::transmute::<*const $t, [usize; 2]>;)+
+            }
+        };
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                $crate::_core::mem::size_of::<*const $t>()
+                    > $crate::_core::mem::size_of::<usize>(),
+                "{}",
+                $crate::_core::concat!(
+                    "pointers to `", $crate::_core::stringify!($t),
+                    "` are not fat"
+                ),
+            );
+        )+
+    };
+}
+
+/// Asserts the kind of metadata that pointers to types carry.
+///
+/// Each type is paired with one of:
+///
+/// - `()`, for sized types, whose pointers are thin.
+/// - `usize`, for types ending in a slice or `str`, whose pointers hold the
+///   length. This is checked exactly, by requiring pointers to be fat and
+///   castable from slice pointers.
+/// - `vtable`, for trait objects, whose pointers hold a vtable. Since stable
+///   Rust can't inspect pointer metadata, only types written as `dyn <traits>`
+///   are accepted, which are checked to be fat. Structs ending in a trait
+///   object can be checked with [`assert_fat_ptr!`] instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::fmt::Debug;
+///
+/// struct Packet {
+///     len: u16,
+///     data: [u8],
+/// }
+///
+/// assert_ptr_metadata!(
+///     u32 => (),
+///     [u8] => usize,
+///     str => usize,
+///     Packet => usize,
+///     dyn Debug => vtable,
+/// );
+/// ```
+///
+/// The following example fails to compile because trait objects aren't
+/// slices:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_ptr_metadata!(dyn std::any::Any => usize);
+/// ```
+///
+/// The following example fails to compile because pointers to `u32` have no
+/// length:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_ptr_metadata!(u32 => usize);
+/// ```
+///
+/// The following example fails to compile because slices aren't trait
+/// objects:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_ptr_metadata!([u8] => vtable);
+/// ```
+///
+/// [`assert_fat_ptr!`]: macro.assert_fat_ptr.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_ptr_metadata {
+    // Collects the tokens of each type up to its `=>`, so that trait objects
+    // can be told apart.
+    (@munch [$($t:tt)+] => $metadata:tt $(, $($rest:tt)*)?) => {
+        assert_ptr_metadata!(@metadata [$($t)+] $metadata);
+        assert_ptr_metadata!(@munch [] $($($rest)*)?);
+    };
+    (@munch [$($t:tt)*] $x:tt $($rest:tt)*) => {
+        assert_ptr_metadata!(@munch [$($t)* $x] $($rest)*);
+    };
+    (@munch []) => {};
+    (@munch [$($t:tt)+]) => {
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "expected `=> <metadata>` after `",
+            $crate::_core::stringify!($($t)+), "`"
+        ));
+    };
+    (@metadata [dyn $($t:tt)+] vtable) => {
+        assert_fat_ptr!(dyn $($t)+);
+    };
+    (@metadata [$($t:tt)+] vtable) => {
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "expected a trait object with `vtable` metadata, found `",
+            $crate::_core::stringify!($($t)+), "`"
+        ));
+    };
+    (@metadata [$($t:tt)+] $metadata:tt) => {
+        assert_ptr_metadata!(@check ($($t)+) $metadata);
+    };
+    (@check ($t:ty) ()) => {
+        assert_thin_ptr!($t);
+    };
+    (@check ($t:ty) usize) => {
+        // Slice pointers can be cast to any thin pointer, but only to fat
+        // pointers with the same metadata.
+        assert_fat_ptr!($t);
+        const _: fn(*const [()]) -> *const $t = |ptr| ptr as *const $t;
+    };
+    (@check ($t:ty) $other:tt) => {
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "expected `()`, `usize`, or `vtable`, found `",
+            $crate::_core::stringify!($other), "`"
+        ));
+    };
+    ($($tokens:tt)+) => {
+        assert_ptr_metadata!(@munch [] $($tokens)+);
+    };
+}
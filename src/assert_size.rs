@@ -10,20 +10,55 @@
 /// [`assert_size_eq_ptr`](macro.assert_size_eq_ptr.html). Instead of specifying
 /// types to compare, values' sizes can be directly compared against each other.
 ///
+/// Sizes can also be ordered via [`assert_size_lt`](macro.assert_size_lt.html),
+/// [`assert_size_le`](macro.assert_size_le.html), and friends.
+///
+/// # Generic Types
+///
+/// Types that depend on generic parameters can't be compared directly. Instead,
+/// `fn <name> for(<generics>)` defines a function which checks the types each
+/// time it's instantiated, which is done either by calling it from generic
+/// code or via [`assert_instantiated!`](macro.assert_instantiated.html):
+///
+/// ```skip
+/// assert_size_eq!(fn <name> for(<generics>) <type>, <type>, ...);
+/// ```
+///
+/// The other `assert_size_*` and `assert_align_*` comparisons accept this form
+/// as well.
+///
 /// # Examples
 ///
 /// These three types, despite being very different, all have the same size:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_size_eq!([u8; 4], (u16, u16), u32);
 /// ```
 ///
+/// A wrapper can be checked to not add any overhead, no matter what it wraps:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// # use std::marker::PhantomData;
+/// struct Wrapper<T>(T, PhantomData<*const u8>);
+///
+/// impl<T> Wrapper<T> {
+///     fn new(value: T) -> Self {
+///         // Checked for every `T` that a `Wrapper` is created with.
+///         wrapper_size::<T>();
+///         Wrapper(value, PhantomData)
+///     }
+/// }
+///
+/// assert_size_eq!(fn wrapper_size for(T) Wrapper<T>, T);
+/// ```
+///
 /// The following example fails to compile because `u32` has 4 times the size of
 /// `u8`:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_size_eq!(u32, u8);
 /// ```
 ///
@@ -32,6 +67,10 @@
 /// [`u32`]: https://doc.rust-lang.org/std/primitive.u32.html
 #[macro_export]
 macro_rules! assert_size_eq {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(size_of each ==;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
     ($x:ty, $($xs:ty),+ $(,)?) => {
         const _: fn() = || {
             $(let _ = $crate::_core::mem::transmute::<$x, $xs>;)+
@@ -54,6 +93,208 @@ macro_rules! assert_eq_size {
     };
 }
 
+/// Asserts that types are **not** equal in size.
+///
+/// # Examples
+///
+/// A `u16` is not the same size as a `u32`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_ne!(u16, u32, u64);
+/// ```
+///
+/// The following example fails to compile because `[u8; 4]` has the same size
+/// as `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_ne!([u8; 4], u32);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_ne {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(size_of each !=;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
+    ($x:ty, $($y:ty),+ $(,)?) => {
+        const _: fn() = || {
+            use $crate::_core::mem::size_of;
+            const_assert_ne!(size_of::<$x>() $(, size_of::<$y>())+);
+        };
+    };
+}
+
+/// Asserts that the types' sizes are less than each other.
+///
+/// Each size is compared with the one after it, and the sizes are revealed in
+/// error messages just like with
+/// [`const_assert_eq_usize!`](macro.const_assert_eq_usize.html).
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_lt!(u8, u16, [u8; 3], u32);
+/// ```
+///
+/// The following example fails to compile because `(u8, u32)` is padded to 8
+/// bytes, which is shown in the error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_lt!((u8, u32), [u8; 6]);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_lt {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(size_of chain <;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
+    ($x:ty, $($y:ty),+ $(,)?) => {
+        const _: fn() = || {
+            use $crate::_core::mem::size_of;
+            const_assert_lt_usize!(size_of::<$x>() $(, size_of::<$y>())+);
+        };
+    };
+}
+
+/// Asserts that the types' sizes are less than or equal to each other.
+///
+/// # Examples
+///
+/// Messages can be checked to fit within a fixed buffer:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct SmallMsg {
+///     id: u32,
+///     payload: [u8; 60],
+/// }
+///
+/// assert_size_le!(SmallMsg, [u8; 64]);
+/// ```
+///
+/// The following example fails to compile because `u64` is larger than `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_le!(u8, u64, u32);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_le {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(size_of chain <=;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
+    ($x:ty, $($y:ty),+ $(,)?) => {
+        const _: fn() = || {
+            use $crate::_core::mem::size_of;
+            const_assert_le_usize!(size_of::<$x>() $(, size_of::<$y>())+);
+        };
+    };
+}
+
+/// Asserts that the types' sizes are greater than each other.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_gt!(u64, (u16, u16), u8, ());
+/// ```
+///
+/// The following example fails to compile because `[u16; 2]` has the same size
+/// as `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_gt!([u16; 2], u32);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_gt {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(size_of chain >;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
+    ($x:ty, $($y:ty),+ $(,)?) => {
+        const _: fn() = || {
+            use $crate::_core::mem::size_of;
+            const_assert_gt_usize!(size_of::<$x>() $(, size_of::<$y>())+);
+        };
+    };
+}
+
+/// Asserts that the types' sizes are greater than or equal to each other.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_ge!(u64, [u8; 8], u32);
+/// ```
+///
+/// The following example fails to compile because `u16` is smaller than
+/// `char`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size_ge!(u16, char);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size_ge {
+    ($vis:vis fn $name:ident for($($generic:tt)*) $x:ty, $($y:ty),+ $(,)?) => {
+        $crate::_assert_layout_for!(size_of chain >=;
+            $vis fn $name [$($generic)*] $x, $($y),+);
+    };
+    ($x:ty, $($y:ty),+ $(,)?) => {
+        const _: fn() = || {
+            use $crate::_core::mem::size_of;
+            const_assert_ge_usize!(size_of::<$x>() $(, size_of::<$y>())+);
+        };
+    };
+}
+
+/// Asserts that a type's size compares to a constant.
+///
+/// The comparison is written as `<type> <op> <expr>`, where `<op>` is any of
+/// `==`, `!=`, `<`, `<=`, `>`, or `>=`, or as `<type> in <range>` with a range
+/// like `8..=32` or `..64`. If the check fails, the error shows the type's
+/// actual size next to the expected bound.
+///
+/// The type is split from the expression at the last comparison operator, so
+/// expressions containing `<` or `>` must be wrapped in parentheses.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size!(u128 == 16);
+/// assert_size!(Option<Box<u8>> == (core::mem::size_of::<usize>()));
+/// assert_size!([u32; 4] in 8..=32);
+/// ```
+///
+/// The following example fails to compile because `(u8, u64)` is padded to 16
+/// bytes, which is shown in the error as the size of an array:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size!((u8, u64) <= 9);
+/// ```
+///
+/// Similarly, a range reports whichever bound isn't met:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_size!([u8; 64] in 8..32);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_size {
+    ($($tokens:tt)+) => {
+        _assert_layout!(size_of: $($tokens)+);
+    };
+}
+
 /// Asserts that values pointed to are equal in size.
 ///
 /// # Examples
@@ -62,7 +303,7 @@ macro_rules! assert_eq_size {
 /// and ensuring the underlying values are the same size.
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// fn operation(x: &(u32, u32), y: &[u16; 4]) {
 ///     assert_size_eq_ptr!(x, y);
 ///     // ...
@@ -73,7 +314,7 @@ macro_rules! assert_eq_size {
 /// lengths have different sizes:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions;
+/// # #[macro_use] extern crate static_assertions_next;
 /// # fn main() {
 /// static BYTES: &[u8; 4] = &[
 ///     /* ... */
@@ -123,7 +364,7 @@ macro_rules! assert_eq_size_ptr {
 /// # Examples
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions;
+/// # #[macro_use] extern crate static_assertions_next;
 /// # fn main() {
 /// struct Byte(u8);
 ///
@@ -138,7 +379,7 @@ macro_rules! assert_eq_size_ptr {
 /// Even though both values are 0, they are of types with different sizes:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions;
+/// # #[macro_use] extern crate static_assertions_next;
 /// # fn main() {
 /// assert_size_eq_val!(0u8, 0u32);
 /// # }
@@ -0,0 +1,93 @@
+/// Asserts that slices of one type can be reinterpreted as slices of another.
+///
+/// For `[A] => [B]`, this checks that the size of `A` is a whole multiple of
+/// the size of `B`, so that every `[A]` covers a whole number of `B`s, and that
+/// `B` is no more aligned than `A`. This complements
+/// [`assert_size_eq_ptr!`], which only handles single values.
+///
+/// The `[A] <=> [B]` form checks both directions. When the element sizes
+/// differ, casting from the smaller elements only works for lengths that are a
+/// multiple of the ratio, which the error shows as the size of an array.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[repr(C, align(4))]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// assert_slice_castable!([u32] => [u8], [u64] => [u16], [Rgba] => [[u8; 4]]);
+/// assert_slice_castable!([Rgba] <=> [u32], [f32] <=> [i32]);
+/// ```
+///
+/// The following example fails to compile because `u32` is more aligned than
+/// `[u8; 4]`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_slice_castable!([[u8; 4]] => [u32]);
+/// ```
+///
+/// The following example fails to compile because a `[u8]` must have a
+/// multiple of 3 elements to be cast to `[[u8; 3]]`, which is shown in the
+/// error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_slice_castable!([[u8; 3]] <=> [u8]);
+/// ```
+///
+/// [`assert_size_eq_ptr!`]: macro.assert_size_eq_ptr.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_slice_castable {
+    (@cast ($a:ty) ($b:ty)) => {
+        const _: () = {
+            $crate::_core::assert!(
+                $crate::_util::cast_multiple(
+                    $crate::_core::mem::size_of::<$a>(),
+                    $crate::_core::mem::size_of::<$b>(),
+                ) == 1,
+                "{}",
+                $crate::_core::concat!(
+                    "`[", $crate::_core::stringify!($a), "]` can't be cast to `[",
+                    $crate::_core::stringify!($b), "]`, since the size of `",
+                    $crate::_core::stringify!($a),
+                    "` is not a multiple of the size of `",
+                    $crate::_core::stringify!($b), "`"
+                ),
+            );
+            const _: [(); 1] = [(); $crate::_util::cast_multiple(
+                $crate::_core::mem::size_of::<$a>(),
+                $crate::_core::mem::size_of::<$b>(),
+            )];
+
+            $crate::_core::assert!(
+                $crate::_core::mem::align_of::<$b>()
+                    <= $crate::_core::mem::align_of::<$a>(),
+                "{}",
+                $crate::_core::concat!(
+                    "`[", $crate::_core::stringify!($a), "]` can't be cast to `[",
+                    $crate::_core::stringify!($b), "]`, since `",
+                    $crate::_core::stringify!($b), "` is more aligned than `",
+                    $crate::_core::stringify!($a), "`"
+                ),
+            );
+        };
+    };
+
+    ([$a:ty] => [$b:ty] $(, $($rest:tt)*)?) => {
+        assert_slice_castable!(@cast ($a) ($b));
+        $(assert_slice_castable!($($rest)*);)?
+    };
+    ([$a:ty] <=> [$b:ty] $(, $($rest:tt)*)?) => {
+        assert_slice_castable!(@cast ($a) ($b));
+        assert_slice_castable!(@cast ($b) ($a));
+        $(assert_slice_castable!($($rest)*);)?
+    };
+    () => {};
+}
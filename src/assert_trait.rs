@@ -8,7 +8,7 @@
 /// All types that implement [`Copy`] must implement [`Clone`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_trait_sub_all!(Copy: Clone);
 /// ```
 ///
@@ -16,7 +16,7 @@
 /// [`PartialOrd`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_trait_sub_all!(Ord: PartialEq, Eq, PartialOrd);
 /// ```
 ///
@@ -24,7 +24,7 @@
 /// [`PartialOrd`]:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_trait_sub_all!(PartialOrd: Eq);
 /// ```
 ///
@@ -54,7 +54,7 @@ macro_rules! assert_trait_sub_all {
 /// single line:
 ///
 /// ```
-/// # use static_assertions::assert_trait_super_all;
+/// # use static_assertions_next::assert_trait_super_all;
 /// trait A: Copy {}
 /// trait B: Copy {}
 ///
@@ -65,7 +65,7 @@ macro_rules! assert_trait_sub_all {
 /// [`assert_trait_sub_all!`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// # trait A: Copy {}
 /// # trait B: Copy {}
 /// assert_trait_sub_all!(A: Copy);
@@ -76,7 +76,7 @@ macro_rules! assert_trait_sub_all {
 /// [`Copy`]:
 ///
 /// ```compile_fail
-/// # use static_assertions::assert_trait_super_all;
+/// # use static_assertions_next::assert_trait_super_all;
 /// # trait A: Copy {}
 /// # trait B: Copy {}
 /// trait C {}
@@ -104,14 +104,14 @@ macro_rules! assert_trait_super_all {
 /// All types that implement [`Copy`] must implement [`Clone`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_trait_sub_any!(Copy: Clone);
 /// ```
 ///
 /// All types that implement [`Ord`] must implement [`Eq`], but don't have to implement [`Clone`]:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_trait_sub_any!(Ord: Eq, Clone);
 /// ```
 ///
@@ -119,7 +119,7 @@ macro_rules! assert_trait_super_all {
 /// [`PartialOrd`]:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_trait_sub_any!(PartialOrd: Eq, Clone);
 /// ```
 ///
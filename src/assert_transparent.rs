@@ -0,0 +1,57 @@
+/// Asserts that types have the same size and alignment as the types they wrap.
+///
+/// This combines [`assert_size_eq!`] and [`assert_align_eq!`] for each
+/// `Outer => Inner` pair, which is what's needed for newtypes that are passed
+/// across FFI or transmuted in slices.
+///
+/// Note that this only checks the layout of the types. Whether the wrapper is
+/// `#[repr(transparent)]`, and hence has the same ABI as the wrapped type, is
+/// checked by the `transparent` predicate of the `proc` feature's `#[assert]`
+/// attribute.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::num::NonZeroU64;
+///
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// #[repr(transparent)]
+/// struct UserId(NonZeroU64);
+///
+/// assert_transparent!(Meters => f64, UserId => NonZeroU64);
+/// ```
+///
+/// The following example fails to compile because `Tagged` is larger than the
+/// `u32` it wraps:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Tagged(u32, bool);
+///
+/// assert_transparent!(Tagged => u32);
+/// ```
+///
+/// The following example fails to compile because `Bytes` has an alignment of
+/// 1 despite being as large as `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Bytes([u8; 4]);
+///
+/// assert_transparent!(Bytes => u32);
+/// ```
+///
+/// [`assert_size_eq!`]: macro.assert_size_eq.html
+/// [`assert_align_eq!`]: macro.assert_align_eq.html
+#[macro_export(local_inner_macros)]
+macro_rules! assert_transparent {
+    ($($x:ty => $y:ty),+ $(,)?) => {
+        $(
+            assert_size_eq!($x, $y);
+            assert_align_eq!($x, $y);
+        )+
+    };
+}
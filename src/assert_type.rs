@@ -7,7 +7,7 @@
 /// types like [`c_float`] will always alias the same type.
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// use std::os::raw::c_float;
 ///
 /// assert_type_eq_all!(c_float, f32);
@@ -17,7 +17,7 @@
 /// use `'static` in that case:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions;
+/// # #[macro_use] extern crate static_assertions_next;
 /// # fn main() {
 /// type Buf<'a> = &'a [u8];
 ///
@@ -29,7 +29,7 @@
 /// refer to the same type:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_type_eq_all!(String, str);
 /// ```
 ///
@@ -37,7 +37,7 @@
 /// implementations.
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_type_eq_all!(str, String);
 /// ```
 ///
@@ -73,7 +73,7 @@ macro_rules! assert_type_eq_all {
 /// Rust has all sorts of slices, but they represent different types of data:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// assert_type_ne_all!([u8], [u16], str);
 /// ```
 ///
@@ -81,7 +81,7 @@ macro_rules! assert_type_eq_all {
 /// for [`u8`]:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// use std::os::raw::c_uchar;
 ///
 /// assert_type_ne_all!(c_uchar, u8, u32);
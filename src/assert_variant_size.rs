@@ -0,0 +1,130 @@
+/// Asserts that the fields of each variant of an enum take up at most the
+/// given number of bytes.
+///
+/// Every variant must be listed along with the types of its fields, which are
+/// checked against the enum's definition. The size of a variant is that of a
+/// tuple of its field types, so this doesn't include the enum's discriminant.
+///
+/// The `proc` feature's `#[assert(max_variant_size = N)]` attribute performs
+/// the same checks, but reads the variants from the enum's definition.
+///
+/// # Examples
+///
+/// Unlike Clippy's `large_enum_variant` lint, this can't be silenced:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// enum Event {
+///     Click(u32, u32),
+///     Key { code: u32, shift: bool },
+///     Paste(Box<str>),
+///     Quit,
+/// }
+///
+/// assert_variant_size_le!(Event:
+///     Click(u32, u32),
+///     Key { code: u32, shift: bool },
+///     Paste(Box<str>),
+///     Quit;
+///     16
+/// );
+/// ```
+///
+/// The following example fails to compile because `Load` is 8 bytes larger
+/// than 16. The excess is shown in the error as the size of an array:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// enum Message {
+///     Ping,
+///     Load([u64; 3]),
+/// }
+///
+/// assert_variant_size_le!(Message: Ping, Load([u64; 3]); 16);
+/// ```
+///
+/// The following example fails to compile because `Quit` isn't listed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// enum Event {
+///     Click(u32, u32),
+///     Quit,
+/// }
+///
+/// assert_variant_size_le!(Event: Click(u32, u32); 16);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_variant_size_le {
+    // Collects `[<variant> (<field types>) <kind>]` for each variant.
+    (@variants $t:tt [$($acc:tt)*] , $($rest:tt)*) => {
+        assert_variant_size_le!(@variants $t [$($acc)*] $($rest)*);
+    };
+    (@variants $t:tt [$($acc:tt)*]
+        $v:ident ($($ft:ty),* $(,)?) $($rest:tt)*
+    ) => {
+        assert_variant_size_le!(@variants $t
+            [$($acc)* [$v ($($ft),*) (tuple)]] $($rest)*);
+    };
+    (@variants $t:tt [$($acc:tt)*]
+        $v:ident { $($f:ident: $ft:ty),* $(,)? } $($rest:tt)*
+    ) => {
+        assert_variant_size_le!(@variants $t
+            [$($acc)* [$v ($($ft),*) ({ $($f),* })]] $($rest)*);
+    };
+    (@variants $t:tt [$($acc:tt)*] $v:ident $($rest:tt)*) => {
+        assert_variant_size_le!(@variants $t [$($acc)* [$v () ()]] $($rest)*);
+    };
+    (@variants ($t:ty) [$([$v:ident ($($ft:ty),*) $kind:tt])*]
+        ; $limit:expr $(,)?
+    ) => {
+        const _: () = {
+            // The enum is only referred to as `Self`, since a name for it
+            // could hide a type that the field types refer to.
+            trait VariantSizeLe {
+                const VARIANTS: ();
+            }
+
+            impl VariantSizeLe for $t {
+                const VARIANTS: () = {
+                    // Ensures that every variant is listed.
+                    let _: fn(&Self) = |value| match value {
+                        $(Self::$v { .. } => {})*
+                    };
+                    $(assert_variant_size_le!(@kind $v $kind ($($ft),*));)*
+                };
+            }
+
+            $(
+                const _: () = $crate::_core::assert!(
+                    $crate::_core::mem::size_of::<($($ft,)*)>() <= $limit,
+                    "{}",
+                    $crate::_core::concat!(
+                        "`", $crate::_core::stringify!($t), "::",
+                        $crate::_core::stringify!($v),
+                        "` is larger than the limit"
+                    ),
+                );
+                const _: [(); 0] = [();
+                    $crate::_core::mem::size_of::<($($ft,)*)>()
+                        .saturating_sub($limit)
+                ];
+            )*
+        };
+    };
+
+    // Ensures that the given field types are those of the variant.
+    (@kind $v:ident (tuple) ($($ft:ty),*)) => {
+        let _: fn($($ft),*) -> Self = Self::$v;
+    };
+    (@kind $v:ident ({ $($f:ident),* }) ($($ft:ty),*)) => {
+        let _: fn($($ft),*) -> Self = |$($f),*| Self::$v { $($f),* };
+    };
+    (@kind $v:ident () ()) => {
+        let _: fn() -> Self = || Self::$v;
+    };
+
+    ($t:ty: $($rest:tt)+) => {
+        assert_variant_size_le!(@variants ($t) [] $($rest)+);
+    };
+}
@@ -0,0 +1,108 @@
+/// Asserts that types are valid when all of their bytes are zero.
+///
+/// This requires the types to implement [`Zeroable`], which is implemented for
+/// primitives and arrays of zeroable types, and which can be derived for
+/// structs and unions with the `proc` feature. Deriving it checks each field,
+/// so that errors name the field that isn't zeroable.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::num::NonZeroU32;
+///
+/// assert_zeroable!(u32, [f64; 4], Option<NonZeroU32>, *const u8);
+/// ```
+///
+/// With the `proc` feature, the fields of a struct are checked recursively:
+///
+#[cfg_attr(feature = "proc", doc = "```")]
+#[cfg_attr(not(feature = "proc"), doc = "```ignore")]
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     flags: [u8; 4],
+///     next: *const Header,
+/// }
+///
+/// assert_zeroable!(Header);
+/// ```
+///
+/// The following example fails to compile because references can't be null:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_zeroable!(&'static u8);
+/// ```
+///
+/// The following example fails to compile because `bool` is rejected, even
+/// though `false` is all zeros:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_zeroable!(bool);
+/// ```
+///
+/// [`Zeroable`]: trait.Zeroable.html
+#[macro_export]
+macro_rules! assert_zeroable {
+    ($($t:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert_zeroable<T: $crate::Zeroable>() {}
+            $(assert_zeroable::<$t>();)+
+        };
+    };
+}
+
+/// Asserts that types are valid for any bit pattern.
+///
+/// This requires the types to implement [`AnyBitPattern`], which is
+/// implemented for integers, floats, and arrays of them, and which can be
+/// derived for structs and unions with the `proc` feature. Deriving it checks
+/// each field, so that errors name the field that isn't valid for any bit
+/// pattern.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::mem::MaybeUninit;
+///
+/// assert_any_bit_pattern!(u8, [i64; 2], f32, MaybeUninit<bool>);
+/// ```
+///
+/// With the `proc` feature, the fields of a struct are checked recursively:
+///
+#[cfg_attr(feature = "proc", doc = "```")]
+#[cfg_attr(not(feature = "proc"), doc = "```ignore")]
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// #[derive(Zeroable, AnyBitPattern)]
+/// #[repr(C)]
+/// struct Sample {
+///     time: u64,
+///     channels: [f32; 2],
+/// }
+///
+/// assert_any_bit_pattern!(Sample);
+/// ```
+///
+/// The following example fails to compile because only 0 and 1 are valid
+/// `bool` values:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_any_bit_pattern!(bool);
+/// ```
+///
+/// [`AnyBitPattern`]: trait.AnyBitPattern.html
+#[macro_export]
+macro_rules! assert_any_bit_pattern {
+    ($($t:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert_any_bit_pattern<T: $crate::AnyBitPattern>() {}
+            $(assert_any_bit_pattern::<$t>();)+
+        };
+    };
+}
@@ -0,0 +1,180 @@
+/// Asserts that types are zero-sized.
+///
+/// On failure, the error names the type and shows its actual size as the size
+/// of an array.
+///
+/// # Syntax
+///
+/// ```skip
+/// assert_zst!(<type>, ...);
+/// assert_zst!(fn <name> for(<generics>) <type>, ...);
+/// assert_zst!(for(<generics>) <type>, ...);
+/// ```
+///
+/// The `fn <name> for(...)` form checks generic types each time the function
+/// is instantiated, as with
+/// [`assert_size_eq!`](macro.assert_size_eq.html#generic-types). The unnamed
+/// `for(...)` form declares the generics itself and proves that the types are
+/// zero-sized for every choice of them, which fails if their size may depend
+/// on the generics.
+///
+/// # Examples
+///
+/// Marker types cost nothing to pass around:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// # use std::marker::PhantomData;
+/// struct Token;
+/// struct Marker<T>(PhantomData<T>);
+///
+/// assert_zst!(Token, (), [u64; 0], Marker<String>);
+/// assert_zst!(for(T) Marker<T>);
+/// ```
+///
+/// Generic code can check its own parameters by calling a named check:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_zst!(fn zst for(T) T);
+///
+/// fn new_token<T: Default>() -> T {
+///     zst::<T>();
+///     T::default()
+/// }
+/// ```
+///
+/// The following example fails to compile because `Handle` takes up 4 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Handle(u32);
+///
+/// assert_zst!(Handle);
+/// ```
+///
+/// The following example fails to compile because `Wrapper<T>` is only
+/// zero-sized for some `T`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Wrapper<T>(T);
+///
+/// assert_zst!(for(T) Wrapper<T>);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_zst {
+    (@check $($t:ty),+) => {
+        $(
+            const {
+                $crate::_core::assert!(
+                    $crate::_core::mem::size_of::<$t>() == 0,
+                    "{}",
+                    $crate::_core::concat!(
+                        "`", $crate::_core::stringify!($t), "` is not zero-sized"
+                    ),
+                )
+            };
+        )+
+    };
+    ($vis:vis fn $name:ident for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            assert_zst!(@check $($t),+);
+        }
+    };
+    (for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_zst<$($generic)*>() {
+                // Sizes are compared before instantiation, and are only known
+                // if they don't depend on the generics.
+                $(let _ = $crate::_core::mem::transmute::<$t, ()>;)+
+            }
+        };
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                $crate::_core::mem::size_of::<$t>() == 0,
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($t), "` is not zero-sized"
+                ),
+            );
+            const _: [(); 0] = [(); $crate::_core::mem::size_of::<$t>()];
+        )+
+    };
+}
+
+/// Asserts that types are **not** zero-sized.
+///
+/// This takes the same forms as [`assert_zst!`](macro.assert_zst.html).
+/// However, a nonzero size can't be proven for every choice of generics, so
+/// the unnamed `for(...)` form only checks that the types are well-formed. To
+/// check generic types, use the `fn <name> for(...)` form.
+///
+/// # Examples
+///
+/// Handles must hold onto something:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Handle(u32);
+/// struct Slot<T>(Option<T>);
+///
+/// assert_not_zst!(Handle, Option<()>, &());
+/// assert_not_zst!(fn slot_not_zst for(T) Slot<T>);
+/// ```
+///
+/// The following example fails to compile because `Handle` is empty:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// struct Handle {}
+///
+/// assert_not_zst!(Handle);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_not_zst {
+    (@check $($t:ty),+) => {
+        $(
+            const {
+                $crate::_core::assert!(
+                    $crate::_core::mem::size_of::<$t>() != 0,
+                    "{}",
+                    $crate::_core::concat!(
+                        "`", $crate::_core::stringify!($t), "` is zero-sized"
+                    ),
+                )
+            };
+        )+
+    };
+    ($vis:vis fn $name:ident for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            assert_not_zst!(@check $($t),+);
+        }
+    };
+    (for($($generic:tt)*) $($t:ty),+ $(,)?) => {
+        const _: () = {
+            // Never instantiated, so this only checks that the types are
+            // well-formed.
+            #[allow(dead_code)]
+            fn assert_not_zst<$($generic)*>() {
+                assert_not_zst!(@check $($t),+);
+            }
+        };
+    };
+    ($($t:ty),+ $(,)?) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                $crate::_core::mem::size_of::<$t>() != 0,
+                "{}",
+                $crate::_core::concat!(
+                    "`", $crate::_core::stringify!($t), "` is zero-sized"
+                ),
+            );
+        )+
+    };
+}
@@ -0,0 +1,100 @@
+use core::{
+    cell::{Cell, UnsafeCell},
+    marker::{PhantomData, PhantomPinned},
+    mem::{ManuallyDrop, MaybeUninit},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8,
+        NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64,
+        NonZeroU8, NonZeroUsize, Wrapping,
+    },
+    ptr::NonNull,
+};
+
+/// Types that are valid when all of their bytes are zero.
+///
+/// This is checked by [`assert_zeroable!`] and can be implemented for structs
+/// and unions with `#[derive(Zeroable)]` when the `proc` feature is enabled,
+/// which requires every field to be `Zeroable`.
+///
+/// Types with invalid bit patterns, such as `bool`, `char`, and enums, are
+/// conservatively not `Zeroable`, nor are references, `NonZero*` integers,
+/// and `NonNull`. Types that have the latter as `Option` payloads are
+/// zeroable as `None`.
+///
+/// # Safety
+///
+/// An all-zero bit pattern must be a valid value of the type.
+///
+/// [`assert_zeroable!`]: macro.assert_zeroable.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not known to be valid when zeroed",
+    label = "`{Self}` is not `Zeroable`"
+)]
+pub unsafe trait Zeroable {}
+
+/// Types that are valid for any bit pattern, such as integers and floats.
+///
+/// This is checked by [`assert_any_bit_pattern!`] and can be implemented for
+/// structs and unions with `#[derive(Zeroable, AnyBitPattern)]` when the
+/// `proc` feature is enabled, which requires every field to be
+/// `AnyBitPattern`.
+///
+/// Padding is allowed, since reading it isn't needed to construct a value.
+///
+/// # Safety
+///
+/// Every bit pattern of the type's size must be a valid value of the type.
+///
+/// [`assert_any_bit_pattern!`]: macro.assert_any_bit_pattern.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not known to be valid for any bit pattern",
+    label = "`{Self}` is not `AnyBitPattern`"
+)]
+pub unsafe trait AnyBitPattern: Zeroable {}
+
+macro_rules! impl_bits {
+    (@impl $trait:ident [$($t:ty),+ $(,)?]) => {
+        $(unsafe impl $trait for $t {})+
+    };
+    ($($trait:ident)&+: $types:tt) => {
+        $(impl_bits!(@impl $trait $types);)+
+    };
+}
+
+impl_bits!(Zeroable & AnyBitPattern: [
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, (), PhantomPinned,
+    Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>,
+    Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>,
+    Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>,
+    Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>,
+]);
+
+unsafe impl<T: ?Sized> Zeroable for PhantomData<T> {}
+unsafe impl<T: ?Sized> AnyBitPattern for PhantomData<T> {}
+
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+unsafe impl<T> AnyBitPattern for MaybeUninit<T> {}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+unsafe impl<T: AnyBitPattern, const N: usize> AnyBitPattern for [T; N] {}
+
+unsafe impl<T: Zeroable> Zeroable for Wrapping<T> {}
+unsafe impl<T: AnyBitPattern> AnyBitPattern for Wrapping<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for ManuallyDrop<T> {}
+unsafe impl<T: AnyBitPattern> AnyBitPattern for ManuallyDrop<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for Cell<T> {}
+unsafe impl<T: AnyBitPattern> AnyBitPattern for Cell<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for UnsafeCell<T> {}
+unsafe impl<T: AnyBitPattern> AnyBitPattern for UnsafeCell<T> {}
+
+// Null pointers, which are also `None` for the non-null pointer types.
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+unsafe impl<T> Zeroable for Option<&T> {}
+unsafe impl<T> Zeroable for Option<&mut T> {}
+unsafe impl<T> Zeroable for Option<NonNull<T>> {}
@@ -15,7 +15,7 @@
 /// generated via meta-programming.
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const VALUE: i32 = // ...
 /// # 3;
 ///
@@ -25,7 +25,7 @@
 /// Inputs are type-checked as booleans:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const_assert!(!0);
 /// ```
 ///
@@ -43,7 +43,7 @@
 /// identity property:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const_assert!(5 * 5 == 5);
 /// ```
 ///
@@ -67,7 +67,7 @@ macro_rules! const_assert {
 /// This works as a shorthand for `const_assert!(a == b)`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const TWO: i32 = 2;
 ///
 /// const_assert_eq!(TWO * TWO, TWO + TWO);
@@ -76,7 +76,7 @@ macro_rules! const_assert {
 /// Just because 2 × 2 = 2 + 2 doesn't mean it holds true for other numbers:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const_assert_eq!(4 + 4, 4 * 4);
 /// ```
 #[macro_export(local_inner_macros)]
@@ -107,7 +107,7 @@ macro_rules! const_assert_eq_usize {
 /// This works as a shorthand for `const_assert!(a != b)`:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const NUM: usize = 32;
 ///
 /// const_assert_ne!(NUM * NUM, 64);
@@ -116,7 +116,7 @@ macro_rules! const_assert_eq_usize {
 /// The following example fails to compile because 2 is magic and 2 × 2 = 2 + 2:
 ///
 /// ```compile_fail
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const_assert_ne!(2 + 2, 2 * 2);
 /// ```
 #[macro_export(local_inner_macros)]
@@ -177,3 +177,253 @@ macro_rules! const_assert_ge {
         const_assert_ge!(@build $($y),+);
     };
 }
+
+/// Asserts that constants of type
+/// [`usize`](https://doc.rust-lang.org/std/primitive.usize.html) are less than
+/// each other.
+///
+/// This is equivalent to [`const_assert_lt!`](macro.const_assert_lt.html) but
+/// allows for inspecting the values in error messages.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_lt_usize!(1, 2, 4);
+/// ```
+///
+/// The following example fails to compile, with the error showing an array of
+/// size 3 where one of size 2 was expected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_lt_usize!(1 + 2, 2);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_lt_usize {
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        const_assert_lt_usize!(@build $x, $($y),+);
+    };
+    (@build $x:expr) => {};
+    (@build $x:expr, $($y:expr),+) => {
+        _const_assert_usize!($x, <, _head!($($y),+));
+        const_assert_lt_usize!(@build $($y),+);
+    };
+}
+
+/// Asserts that constants of type
+/// [`usize`](https://doc.rust-lang.org/std/primitive.usize.html) are less than
+/// or equal to each other.
+///
+/// This is equivalent to [`const_assert_le!`](macro.const_assert_le.html) but
+/// allows for inspecting the values in error messages.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_le_usize!(1, 2, 2, 4);
+/// ```
+///
+/// The following example fails to compile, with the error showing an array of
+/// size 4 where one of size 3 was expected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_le_usize!(2 + 2, 3);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_le_usize {
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        const_assert_le_usize!(@build $x, $($y),+);
+    };
+    (@build $x:expr) => {};
+    (@build $x:expr, $($y:expr),+) => {
+        _const_assert_usize!($x, <=, _head!($($y),+));
+        const_assert_le_usize!(@build $($y),+);
+    };
+}
+
+/// Asserts that constants of type
+/// [`usize`](https://doc.rust-lang.org/std/primitive.usize.html) are greater
+/// than each other.
+///
+/// This is equivalent to [`const_assert_gt!`](macro.const_assert_gt.html) but
+/// allows for inspecting the values in error messages.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_gt_usize!(4, 2, 1);
+/// ```
+///
+/// The following example fails to compile, with the error showing an array of
+/// size 1 where one of size 2 was expected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_gt_usize!(1, 2);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_gt_usize {
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        const_assert_gt_usize!(@build $x, $($y),+);
+    };
+    (@build $x:expr) => {};
+    (@build $x:expr, $($y:expr),+) => {
+        _const_assert_usize!($x, >, _head!($($y),+));
+        const_assert_gt_usize!(@build $($y),+);
+    };
+}
+
+/// Asserts that constants of type
+/// [`usize`](https://doc.rust-lang.org/std/primitive.usize.html) are greater
+/// than or equal to each other.
+///
+/// This is equivalent to [`const_assert_ge!`](macro.const_assert_ge.html) but
+/// allows for inspecting the values in error messages.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_ge_usize!(4, 4, 2, 1);
+/// ```
+///
+/// The following example fails to compile, with the error showing an array of
+/// size 2 where one of size 3 was expected:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// const_assert_ge_usize!(2, 1 + 2);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! const_assert_ge_usize {
+    ($x:expr, $($y:expr),+ $(,)?) => {
+        const_assert_ge_usize!(@build $x, $($y),+);
+    };
+    (@build $x:expr) => {};
+    (@build $x:expr, $($y:expr),+) => {
+        _const_assert_usize!($x, >=, _head!($($y),+));
+        const_assert_ge_usize!(@build $($y),+);
+    };
+}
+
+/// Asserts that bitfields, given as ranges of bits, fit within an integer
+/// without overlapping.
+///
+/// Each field's range must be a nonempty `Range<u32>`, such as `4..7` or a
+/// constant describing a register field. When fields collide, the error names
+/// both of them. Ending with `; complete` also asserts that the fields cover
+/// every bit of the integer, showing the number of bits covered in the error.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// use std::ops::Range;
+///
+/// const MODE: Range<u32> = 4..7;
+///
+/// assert_bitfields!(u32 { mode: MODE, enable: 7..8, prescale: 8..16 });
+/// assert_bitfields!(u8 { low: 0..4, high: 4..8 }; complete);
+/// ```
+///
+/// The following example fails to compile because `enable` overlaps `mode`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_bitfields!(u32 { mode: 4..7, enable: 6..7 });
+/// ```
+///
+/// The following example fails to compile because `prescale` doesn't fit
+/// within a `u16`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_bitfields!(u16 { mode: 4..7, prescale: 8..24 });
+/// ```
+///
+/// The following example fails to compile because bits 8 to 16 are unused:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
+/// assert_bitfields!(u16 { low: 0..8 }; complete);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! assert_bitfields {
+    ($t:ty { $($f:ident: $range:expr),+ $(,)? } $(;)?) => {
+        assert_bitfields!(@fields ($t) $([$f: $range])+);
+        assert_bitfields!(@pairs ($t) $([$f: $range])+);
+    };
+    ($t:ty { $($f:ident: $range:expr),+ $(,)? }; complete $(;)?) => {
+        assert_bitfields!($t { $($f: $range),+ });
+        #[allow(unknown_lints, clippy::identity_op)]
+        const _: () = $crate::_core::assert!(
+            assert_bitfields!(@covered $($range),+)
+                == $crate::_core::mem::size_of::<$t>() * 8,
+            "{}",
+            $crate::_core::concat!(
+                "bitfields of `", $crate::_core::stringify!($t),
+                "` don't cover all of its bits"
+            ),
+        );
+        #[allow(unknown_lints, clippy::identity_op)]
+        const _: [(); $crate::_core::mem::size_of::<$t>() * 8] =
+            [(); assert_bitfields!(@covered $($range),+)];
+    };
+    (@fields ($t:ty) $([$f:ident: $range:expr])+) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                assert_bitfields!(@range $range).start
+                    < assert_bitfields!(@range $range).end,
+                "{}",
+                $crate::_core::concat!(
+                    "bitfield `", $crate::_core::stringify!($f), "` of `",
+                    $crate::_core::stringify!($t), "` is empty"
+                ),
+            );
+            const _: () = $crate::_core::assert!(
+                assert_bitfields!(@range $range).end as usize
+                    <= $crate::_core::mem::size_of::<$t>() * 8,
+                "{}",
+                $crate::_core::concat!(
+                    "bitfield `", $crate::_core::stringify!($f),
+                    "` doesn't fit within `", $crate::_core::stringify!($t), "`"
+                ),
+            );
+        )+
+    };
+    (@pairs ($t:ty)) => {};
+    (@pairs ($t:ty) [$f:ident: $range:expr] $([$g:ident: $other:expr])*) => {
+        $(
+            const _: () = $crate::_core::assert!(
+                assert_bitfields!(@range $range).end
+                    <= assert_bitfields!(@range $other).start
+                    || assert_bitfields!(@range $other).end
+                        <= assert_bitfields!(@range $range).start,
+                "{}",
+                $crate::_core::concat!(
+                    "bitfields `", $crate::_core::stringify!($f), "` and `",
+                    $crate::_core::stringify!($g), "` of `",
+                    $crate::_core::stringify!($t), "` overlap"
+                ),
+            );
+        )*
+        assert_bitfields!(@pairs ($t) $([$g: $other])*);
+    };
+    (@covered $($range:expr),+) => {
+        0 $(+ assert_bitfields!(@width $range))+
+    };
+    (@width $range:expr) => {
+        assert_bitfields!(@range $range)
+            .end
+            .saturating_sub(assert_bitfields!(@range $range).start) as usize
+    };
+    // Evaluates a range without binding it, since bindings in patterns could
+    // resolve to the caller's constants.
+    (@range $range:expr) => {
+        $crate::_core::convert::identity::<$crate::_core::ops::Range<u32>>($range)
+    };
+}
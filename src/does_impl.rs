@@ -5,7 +5,7 @@
 /// One can mimic `assert_impl!` using this macro:
 ///
 /// ```
-/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # #[macro_use] extern crate static_assertions_next; fn main() {}
 /// const CONDITION: bool = does_impl!(u32: From<u8>);
 ///
 /// const_assert!(CONDITION);
@@ -26,7 +26,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! static_assertions = "1.1.0"
+//! static_assertions_next = "1.1.2"
 //! ```
 //!
 //! and this to your crate root (`main.rs` or `lib.rs`):
@@ -34,7 +34,7 @@
 //! ```
 //! # #[allow(unused_imports)]
 //! #[macro_use]
-//! extern crate static_assertions;
+//! extern crate static_assertions_next;
 //! # fn main() {}
 //! ```
 //!
@@ -42,14 +42,14 @@
 //! having `#[macro_use]` is undesirable.
 //!
 //! ```edition2018
-//! extern crate static_assertions as sa;
+//! extern crate static_assertions_next as sa;
 //!
 //! sa::const_assert!(true);
 //! ```
 //!
 //! ## Procedural Extensions
 //!
-//! As an extension crate [`proc_static_assertions`] adds a number of new
+//! As an extension crate [`proc_static_assertions_next`] adds a number of new
 //! assertions to this. These are implemented as [procedural macros], hence the
 //! "proc" prefix. As a result, they have a bit more visibility over what's
 //! being asserted over than normal macros would.
@@ -58,7 +58,17 @@
 //!
 //! ```toml
 //! [dependencies]
-//! static_assertions = { version = "1.1.0", features = ["proc"] }
+//! static_assertions_next = { version = "1.1.2", features = ["proc"] }
+//! ```
+//!
+//! Every procedural macro is then re-exported from this crate, so it can be
+//! imported via `#[macro_use]` just like the other macros, or by path:
+//!
+//! ```ignore
+//! #[static_assertions_next::assert(size == 4, align == 4)]
+//! struct Foo {
+//!     value: i32,
+//! }
 //! ```
 //!
 //! # Examples
@@ -85,7 +95,7 @@
 //!     <img src="https://buymecoffee.intm.org/img/button-paypal-white.png" alt="Buy me a coffee" height="35">
 //! </a>
 //!
-//! [`proc_static_assertions`]: https://docs.rs/proc_static_assertions
+//! [`proc_static_assertions_next`]: https://docs.rs/proc_static_assertions_next
 //! [procedural macros]: https://doc.rust-lang.org/book/ch19-06-macros.html#procedural-macros-for-generating-code-from-attributes
 //! [Rust 1.37]: https://blog.rust-lang.org/2019/08/15/Rust-1.37.0.html
 //! [2018]: https://blog.rust-lang.org/2018/12/06/Rust-1.31-and-rust-2018.html#rust-2018
@@ -101,10 +111,10 @@
 )]
 #![no_std]
 
-#[cfg(feature = "proc_static_assertions")]
-extern crate proc_static_assertions;
-#[cfg(feature = "proc_static_assertions")]
-pub use proc_static_assertions::assert;
+#[cfg(feature = "proc")]
+extern crate proc_static_assertions_next;
+#[cfg(feature = "proc")]
+pub use proc_static_assertions_next::*;
 
 // This module should never be used publicly and is not part of this crate's
 // semver requirements.
@@ -112,21 +122,43 @@ pub use proc_static_assertions::assert;
 pub extern crate core as _core;
 
 mod assert_align;
+mod assert_atomic;
+mod assert_budget;
+mod assert_cache;
 mod assert_cfg;
+mod assert_drop;
 mod assert_fields;
 mod assert_impl;
+mod assert_instantiated;
+mod assert_layout;
+mod assert_niche;
+mod assert_no_padding;
 mod assert_obj_safe;
+mod assert_offset;
+mod assert_packed_aligned;
+mod assert_ptr;
 mod assert_size;
+mod assert_slice_castable;
 mod assert_trait;
+mod assert_transparent;
 mod assert_type;
+mod assert_variant_size;
+mod assert_zeroable;
+mod assert_zst;
 mod const_assert;
 mod does_impl;
 
-// Utility macros.
+// Bit validity marker traits, which can be derived with the `proc` feature.
+mod bits;
+pub use bits::{AnyBitPattern, Zeroable};
+
+// Utility macros and functions.
 //
-// These macros should also never be used publicly and are not part of this
-// crate's semver requirements.
-mod util;
+// This module should never be used publicly and is not part of this crate's
+// semver requirements.
+#[doc(hidden)]
+#[path = "util.rs"]
+pub mod _util;
 
 // Type-level booleans.
 //
@@ -140,3 +172,11 @@ pub mod _bool;
 // crate's semver requirements.
 #[doc(hidden)]
 pub use _bool::{False, True};
+
+// Niche counting.
+//
+// This module should never be used publicly and is not part of this crate's
+// semver requirements.
+#[doc(hidden)]
+#[path = "niche.rs"]
+pub mod _niche;
@@ -0,0 +1,150 @@
+//! Probing of the niches, i.e. invalid bit patterns, that a type offers.
+//!
+//! Each `Option` that a type is wrapped in takes up one niche, so a type has
+//! at least `N` niches if it can be wrapped in `N` options without growing in
+//! size. Probing stops one past the value that the count is compared against,
+//! so that the cost of an assertion scales with it.
+
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+/// The largest value that niche counts can be compared against.
+pub const MAX: usize = 510;
+
+/// Returns bit `k` of the number of `Option`s to probe for a count compared
+/// against `bound`.
+pub const fn bit(bound: usize, k: u32) -> bool {
+    assert!(
+        bound <= MAX,
+        "niche counts can only be compared against values up to 510",
+    );
+    (bound + 1) >> k & 1 == 1
+}
+
+/// Wraps a type in a fixed number of `Option`s.
+pub trait Block {
+    type Wrap<T>;
+}
+
+/// A single `Option`.
+pub struct One;
+
+/// Twice as many `Option`s as `B`.
+pub struct Double<B>(PhantomData<B>);
+
+impl Block for One {
+    type Wrap<T> = Option<T>;
+}
+
+impl<B: Block> Block for Double<B> {
+    type Wrap<T> = B::Wrap<B::Wrap<T>>;
+}
+
+/// Counts the `Option`s of the block, wrapped around `X`, that don't make `R`
+/// any larger.
+pub trait Fits<R, X>: Block {
+    const COUNT: usize;
+}
+
+impl<R, X> Fits<R, X> for One {
+    const COUNT: usize = (size_of::<Option<X>>() == size_of::<R>()) as usize;
+}
+
+impl<R, X, B> Fits<R, X> for Double<B>
+where
+    B: Fits<R, X> + Fits<R, <B as Block>::Wrap<X>>,
+{
+    const COUNT: usize =
+        <B as Fits<R, X>>::COUNT + <B as Fits<R, B::Wrap<X>>>::COUNT;
+}
+
+/// Counts the niches of `R` that are used by wrapping `X` in the blocks of a
+/// list, each of which is only included if its bit is set.
+pub trait Probe<R, X> {
+    const COUNT: usize;
+}
+
+/// The end of a list of blocks.
+pub struct End;
+
+/// A block followed by the rest of a list.
+pub struct Bit<const SET: bool, B, Rest>(PhantomData<(B, Rest)>);
+
+impl<R, X> Probe<R, X> for End {
+    const COUNT: usize = 0;
+}
+
+impl<R, X, B, Rest> Probe<R, X> for Bit<true, B, Rest>
+where
+    B: Fits<R, X>,
+    Rest: Probe<R, B::Wrap<X>>,
+{
+    const COUNT: usize = B::COUNT + Rest::COUNT;
+}
+
+impl<R, X, B, Rest: Probe<R, X>> Probe<R, X> for Bit<false, B, Rest> {
+    const COUNT: usize = Rest::COUNT;
+}
+
+type B1 = One;
+type B2 = Double<B1>;
+type B4 = Double<B2>;
+type B8 = Double<B4>;
+type B16 = Double<B8>;
+type B32 = Double<B16>;
+type B64 = Double<B32>;
+type B128 = Double<B64>;
+type B256 = Double<B128>;
+
+/// Probes as many `Option`s as the bits, from most to least significant, add
+/// up to.
+pub type Cap<
+    const K8: bool,
+    const K7: bool,
+    const K6: bool,
+    const K5: bool,
+    const K4: bool,
+    const K3: bool,
+    const K2: bool,
+    const K1: bool,
+    const K0: bool,
+> = Bit<
+    K8,
+    B256,
+    Bit<
+        K7,
+        B128,
+        Bit<
+            K6,
+            B64,
+            Bit<
+                K5,
+                B32,
+                Bit<
+                    K4,
+                    B16,
+                    Bit<K3, B8, Bit<K2, B4, Bit<K1, B2, Bit<K0, B1, End>>>>,
+                >,
+            >,
+        >,
+    >,
+>;
+
+/// Returns the number of niches in `$t`, up to one more than `$bound`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _niche_count {
+    ($t:ty, $bound:expr) => {
+        <$crate::_niche::Cap<
+            { $crate::_niche::bit($bound, 8) },
+            { $crate::_niche::bit($bound, 7) },
+            { $crate::_niche::bit($bound, 6) },
+            { $crate::_niche::bit($bound, 5) },
+            { $crate::_niche::bit($bound, 4) },
+            { $crate::_niche::bit($bound, 3) },
+            { $crate::_niche::bit($bound, 2) },
+            { $crate::_niche::bit($bound, 1) },
+            { $crate::_niche::bit($bound, 0) },
+        > as $crate::_niche::Probe<$t, $t>>::COUNT
+    };
+}
@@ -1,3 +1,72 @@
+//! Helpers for the macros of this crate.
+//!
+//! Macros call the functions here rather than declaring their own, since items
+//! declared by an expansion would hide the caller's items of the same name.
+
+/// Returns the size of the field that `project` points to.
+///
+/// The field is projected through a pointer that's never read, which works for
+/// packed types and types that implement `Drop`.
+pub const fn field_size<T, F>(_project: fn(*const T) -> *const F) -> usize {
+    core::mem::size_of::<F>()
+}
+
+/// Returns the largest multiple of `align` that's at most `offset`.
+pub const fn align_down(offset: usize, align: usize) -> usize {
+    offset - offset % align
+}
+
+/// Returns the size of the static that `addr` takes the address of.
+///
+/// Taking the address rather than a reference also works for `static mut`.
+pub const fn size_of_static<T>(_addr: fn() -> *const T) -> usize {
+    core::mem::size_of::<T>()
+}
+
+/// Returns how many values of size `a` make up a whole number of values of
+/// size `b`.
+pub const fn cast_multiple(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let rem = x % y;
+        x = y;
+        y = rem;
+    }
+    // Both sizes are zero if the divisor is.
+    match b.checked_div(x) {
+        Some(multiple) => multiple,
+        None => 1,
+    }
+}
+
+/// Returns whether the byte ranges may share a line when the type is placed at
+/// any multiple of its alignment.
+pub const fn share_line(
+    a: usize,
+    a_size: usize,
+    b: usize,
+    b_size: usize,
+    align: usize,
+    line: usize,
+) -> bool {
+    if a_size == 0 || b_size == 0 {
+        return false;
+    }
+    // Placements repeat every line, or every alignment if larger.
+    let mut base = 0;
+    while base < line {
+        let a_first = (base + a) / line;
+        let a_last = (base + a + a_size - 1) / line;
+        let b_first = (base + b) / line;
+        let b_last = (base + b + b_size - 1) / line;
+        if a_first <= b_last && b_first <= a_last {
+            return true;
+        }
+        base += align;
+    }
+    false
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! _head {
@@ -5,3 +74,192 @@ macro_rules! _head {
         $head
     };
 }
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _const_assert_usize {
+    ($x:expr, $op:tt, $y:expr) => {
+        const_assert!($x $op $y);
+        // Reveals `$x` next to `$y` if the comparison fails. Strict comparisons
+        // can fail with equal values, in which case only the assertion above
+        // reports an error.
+        #[allow(unknown_lints, unused_parens, unused_braces)]
+        const _: [(); $y] = [(); if $x $op $y { $y } else { $x }];
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_budget {
+    ($total:expr, $budget:expr, $message:expr) => {
+        #[allow(unknown_lints, clippy::identity_op)]
+        const _: () = {
+            $crate::_core::assert!($total <= $budget, "{}", $message);
+            const _: [(); $budget] =
+                [(); if $total > $budget { $total } else { $budget }];
+            const _: [(); 0] = [(); $total.saturating_sub($budget)];
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_layout {
+    // `$f` is a `const fn` that measures the type, or `bounded` followed by a
+    // macro that measures it given the value that it's compared against.
+    ([$($f:tt)+]: $($tokens:tt)+) => {
+        _assert_layout!(@split [$($f)+] [] [] $($tokens)+);
+    };
+    ($f:ident: $($tokens:tt)+) => {
+        _assert_layout!(@split [$crate::_core::mem::$f] [] [] $($tokens)+);
+    };
+
+    // Splits the input at its last comparison operator, since types may
+    // contain `<` and `>` themselves. The second bracket holds the most recent
+    // operator followed by the tokens after it.
+    (@split [$($f:tt)+] [$($t:tt)+] [$($rhs:tt)*] in $($range:tt)+) => {
+        _assert_layout!(@range [$($f)+] [$($t)+ $($rhs)*] [] $($range)+);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*] == $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $($rhs)*] [==] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*] != $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $($rhs)*] [!=] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*] <= $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $($rhs)*] [<=] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*] >= $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $($rhs)*] [>=] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*] < $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $($rhs)*] [<] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*] > $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $($rhs)*] [>] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [] $x:tt $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)* $x] [] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)+] $x:tt $($rest:tt)*) => {
+        _assert_layout!(@split [$($f)+] [$($t)*] [$($rhs)+ $x] $($rest)*);
+    };
+    (@split [$($f:tt)+] [$($t:tt)+] [$op:tt $($rhs:tt)+]) => {
+        _assert_layout!(@check [$($f)+] ($($t)+) $op ($($rhs)+));
+    };
+    (@split [$($f:tt)+] [$($t:tt)*] [$($rhs:tt)*]) => {
+        $crate::_core::compile_error!(
+            "expected `<type> <op> <expr>` or `<type> in <range>`"
+        );
+    };
+
+    // Splits a range at `..=` or `..`, either bound of which may be omitted.
+    (@range [$($f:tt)+] [$($t:tt)+] [$($lo:tt)*] ..= $($hi:tt)+) => {
+        _assert_layout!(@lower [$($f)+] ($($t)+) $($lo)*);
+        _assert_layout!(@check [$($f)+] ($($t)+) <= ($($hi)+));
+    };
+    (@range [$($f:tt)+] [$($t:tt)+] [$($lo:tt)*] .. $($hi:tt)+) => {
+        _assert_layout!(@lower [$($f)+] ($($t)+) $($lo)*);
+        _assert_layout!(@check [$($f)+] ($($t)+) < ($($hi)+));
+    };
+    (@range [$($f:tt)+] [$($t:tt)+] [$($lo:tt)+] ..) => {
+        _assert_layout!(@lower [$($f)+] ($($t)+) $($lo)+);
+    };
+    (@range [$($f:tt)+] [$($t:tt)+] [$($lo:tt)*] $x:tt $($rest:tt)*) => {
+        _assert_layout!(@range [$($f)+] [$($t)+] [$($lo)* $x] $($rest)*);
+    };
+    (@range [$($f:tt)+] [$($t:tt)+] [$($lo:tt)*]) => {
+        $crate::_core::compile_error!("expected a range like `8..=32`");
+    };
+
+    (@lower [$($f:tt)+] ($($t:tt)+)) => {};
+    (@lower [$($f:tt)+] ($($t:tt)+) $($lo:tt)+) => {
+        _assert_layout!(@check [$($f)+] ($($t)+) >= ($($lo)+));
+    };
+
+    (@check [bounded $m:ident] ($t:ty) $op:tt ($x:expr)) => {
+        const _: fn() = || {
+            _const_assert_usize!($crate::$m!($t, $x), $op, $x);
+        };
+    };
+    (@check [$($f:tt)+] ($t:ty) $op:tt ($x:expr)) => {
+        const _: fn() = || {
+            _const_assert_usize!($($f)+::<$t>(), $op, $x);
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_layout_by_target {
+    (@arm $t:ty [$($prev:meta),*] _ => compile_error $(,)?) => {
+        _assert_layout_by_target!(@arm $t [$($prev),*]);
+    };
+    (@arm $t:ty [$($prev:meta),*] _ => compile_error($msg:expr) $(,)?) => {
+        #[cfg(not(any($($prev),*)))]
+        $crate::_core::compile_error!($msg);
+    };
+    (@arm $t:ty [$($prev:meta),*]
+        _ => $(size $size:tt)? $(align $align:tt)? $(,)?
+    ) => {
+        _assert_layout_by_target!(@check $t, not(any($($prev),*)),
+            $(size $size)? $(align $align)?);
+    };
+    (@arm $t:ty [$($prev:meta),*]
+        $cfg:meta => $(size $size:tt)? $(align $align:tt)?
+        $(, $($rest:tt)*)?
+    ) => {
+        _assert_layout_by_target!(@check $t, all($cfg, not(any($($prev),*))),
+            $(size $size)? $(align $align)?);
+        _assert_layout_by_target!(@arm $t [$($prev,)* $cfg] $($($rest)*)?);
+    };
+    (@arm $t:ty [$($prev:meta),*]) => {
+        #[cfg(not(any($($prev),*)))]
+        $crate::_core::compile_error!($crate::_core::concat!(
+            "no expected layout of `",
+            $crate::_core::stringify!($t),
+            "` for the current target",
+        ));
+    };
+    (@check $t:ty, $cfg:meta,) => {
+        $crate::_core::compile_error!("expected `size` and/or `align`");
+    };
+    (@check $t:ty, $cfg:meta, $(size $size:tt)? $(align $align:tt)?) => {
+        $(
+            #[cfg($cfg)]
+            _assert_layout!(@check [$crate::_core::mem::size_of] ($t) == ($size));
+        )?
+        $(
+            #[cfg($cfg)]
+            _assert_layout!(@check [$crate::_core::mem::align_of] ($t) == ($align));
+        )?
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _assert_layout_for {
+    (
+        $f:ident $mode:ident $op:tt;
+        $vis:vis fn $name:ident [$($generic:tt)*] $($t:ty),+
+    ) => {
+        #[allow(dead_code)]
+        $vis fn $name<$($generic)*>() {
+            use $crate::_core::mem::$f;
+            _assert_layout_for!(@$mode $f $op; $($t),+);
+        }
+    };
+    // Compares the first type with each of the others.
+    (@each $f:ident $op:tt; $x:ty $(, $y:ty)+) => {
+        $(
+            // Evaluated once per instantiation of the enclosing function.
+            const { $crate::_core::assert!($f::<$x>() $op $f::<$y>()) };
+        )+
+    };
+    // Compares each type with the one after it.
+    (@chain $f:ident $op:tt; $x:ty) => {};
+    (@chain $f:ident $op:tt; $x:ty, $y:ty $(, $rest:ty)*) => {
+        const { $crate::_core::assert!($f::<$x>() $op $f::<$y>()) };
+        _assert_layout_for!(@chain $f $op; $y $(, $rest)*);
+    };
+}
@@ -0,0 +1,41 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::num::NonZeroU32;
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+#[repr(C, align(8))]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+struct Flag(bool);
+
+assert_lock_free!(bool, u8, i8, u16, i16, u32, i32);
+assert_lock_free!(usize, isize, ptr,);
+
+#[cfg(target_has_atomic = "64")]
+assert_lock_free!(u64, i64);
+
+assert_atomic_compatible!(
+    u8 => AtomicU8,
+    [i8; 1] => AtomicI8,
+    Flag => AtomicBool,
+    u16 => AtomicU16,
+    i16 => AtomicI16,
+    Option<NonZeroU32> => AtomicU32,
+    f32 => AtomicI32,
+    usize => AtomicUsize,
+    *const u8 => AtomicIsize,
+);
+
+#[cfg(target_has_atomic = "64")]
+assert_atomic_compatible!(Point => AtomicU64, f64 => AtomicI64,);
@@ -0,0 +1,29 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::ops::Range;
+
+const MODE: Range<u32> = 4..7;
+const ENABLE: Range<u32> = 7..8;
+#[allow(non_upper_case_globals)]
+const range: Range<u32> = 0..1;
+
+assert_bitfields!(u32 {
+    mode: MODE,
+    enable: ENABLE,
+    prescale: 8..16
+});
+assert_bitfields!(u32 {
+    prescale: 8..16,
+    enable: ENABLE,
+    mode: MODE,
+});
+assert_bitfields!(u8 { flag: 7..8 });
+assert_bitfields!(u64 { high: 32..64, low: 0..32 }; complete);
+assert_bitfields!(u8 { a: 0..1, b: 1..3, c: 3..6, d: 6..8, }; complete);
+assert_bitfields!(u128 { wide: 0..128 }; complete);
+assert_bitfields!(usize { tag: 0..2, ptr: 2..usize::BITS }; complete);
+assert_bitfields!(u16 { only: range });
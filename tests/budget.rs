@@ -0,0 +1,39 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::sync::atomic::AtomicU32;
+
+static BUF_A: [u8; 1024] = [0; 1024];
+static BUF_B: [u8; 2048] = [0; 2048];
+static TABLE: [u32; 256] = [0; 256];
+static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+mod config {
+    pub static NAME: &str = "firmware";
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+static size: u8 = 0;
+#[allow(dead_code)]
+static mut SCRATCH: [u8; 64] = [0; 64];
+
+const BUDGET: usize = 4 * 1024;
+
+assert_static_budget!(4096; BUF_A, BUF_B, TABLE);
+assert_static_budget!(BUDGET + 4; BUF_A, BUF_B, TABLE, COUNTER,);
+assert_static_budget!(16; config::NAME);
+assert_static_budget!(1; size);
+assert_static_budget!(64; SCRATCH);
+
+#[allow(dead_code)]
+struct Task {
+    stack: [u8; 96],
+    state: u32,
+}
+
+assert_type_budget!(1024; [Task; 8], u64);
+assert_type_budget!(800; [Task; 8]);
+assert_type_budget!(BUDGET; [u8; 4096], (), [u64; 0],);
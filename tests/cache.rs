@@ -0,0 +1,64 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::sync::atomic::AtomicUsize;
+
+#[allow(dead_code)]
+#[repr(C, align(64))]
+struct Queue {
+    head: AtomicUsize,
+    _pad: [u8; 56],
+    tail: AtomicUsize,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Counters {
+    reads: u64,
+    _pad: [u8; 64],
+    writes: u64,
+}
+
+#[allow(dead_code)]
+#[repr(C, align(128))]
+struct Padded<T>(T);
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Channel {
+    senders: Padded<usize>,
+    receivers: Padded<usize>,
+    empty: (),
+    len: usize,
+}
+
+#[allow(dead_code)]
+#[repr(C, align(64))]
+struct Workers {
+    queue: Vec<usize>,
+    _pad: [u8; 64],
+    idle: AtomicUsize,
+}
+
+impl Drop for Workers {
+    fn drop(&mut self) {}
+}
+
+assert_cache_separated!(Queue: head, tail; line = 64);
+assert_cache_separated!(Queue: tail, head; line = 64);
+assert_cache_separated!(Counters: reads, writes; line = 64);
+assert_cache_separated!(Channel: senders.0, receivers.0; line = 128);
+assert_cache_separated!(Channel: senders, receivers; line = 128);
+assert_cache_separated!(Channel: empty, len; line = 64,);
+assert_cache_separated!(Workers: queue, idle; line = 64);
+
+assert_cache_aligned!(Queue, 64);
+assert_cache_aligned!(Padded<u8>, 128);
+assert_cache_aligned!(Padded<[u8; 129]>, 64);
+assert_cache_aligned!([Queue; 3], 32,);
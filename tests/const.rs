@@ -13,3 +13,8 @@ const FIVE: usize = 5;
 
 const_assert!(FIVE * 2 == 10);
 const_assert!(FIVE > 2);
+
+const_assert_lt_usize!(1, FIVE, FIVE * 2);
+const_assert_le_usize!(1, FIVE, FIVE);
+const_assert_gt_usize!(FIVE * 2, FIVE, 0);
+const_assert_ge_usize!(FIVE, FIVE, 1);
@@ -0,0 +1,61 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+extern crate alloc;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{cell::Cell, marker::PhantomData, mem::ManuallyDrop};
+
+#[allow(dead_code)]
+struct Node<'a> {
+    value: u64,
+    next: Option<&'a Node<'a>>,
+}
+
+#[allow(dead_code)]
+struct Pair<T>(T, T);
+
+#[allow(dead_code)]
+struct Arena<T> {
+    slots: [Option<T>; 4],
+    len: usize,
+}
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+assert_no_drop_glue!(u8, (), [u64; 4], &str, Node, Cell<u32>);
+assert_no_drop_glue!(ManuallyDrop<String>, PhantomData<String>, [String; 0],);
+assert_no_drop_glue!(fn pair_no_drop_glue for(T: Copy) Pair<T>, [T; 2]);
+assert_no_drop_glue!(pub fn ref_no_drop_glue for('a, T: ?Sized + 'a) &'a T, *mut T);
+assert_no_drop_glue!(for(T: Copy) Pair<T>, [T; 2], Arena<T>);
+assert_no_drop_glue!(for('a, T: ?Sized + 'a) &'a T, *mut T, Node<'a>);
+
+assert_needs_drop!(Guard, String, Vec<u8>, Box<u8>, Option<Guard>, [Guard; 1]);
+assert_needs_drop!(fn boxed_needs_drop for(T: ?Sized) Box<T>);
+
+fn alloc<T>(value: T) -> Pair<T>
+where
+    T: Copy,
+{
+    pair_no_drop_glue::<T>();
+    Pair(value, value)
+}
+
+#[test]
+fn generic_statement() {
+    let Pair(a, b) = alloc(3_u16);
+    assert_eq!(a + b, 6);
+}
+
+assert_instantiated!(
+    pair_no_drop_glue::<f64>,
+    ref_no_drop_glue::<str>,
+    boxed_needs_drop::<[u8]>,
+);
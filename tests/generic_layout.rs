@@ -0,0 +1,30 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::marker::PhantomData;
+
+#[allow(dead_code)]
+#[repr(transparent)]
+struct Wrapper<T>(T, PhantomData<*const u8>);
+
+assert_size_eq!(fn wrapper_size for(T) Wrapper<T>, T);
+assert_align_eq!(pub fn wrapper_align for(T: ?Sized) PhantomData<T>, (), [u8; 0]);
+assert_size_le!(fn option_size for(T: Copy) T, Option<T>, Option<Option<T>>);
+assert_size_ne!(fn not_zero for(T) [T; 1], ());
+assert_size_eq!(fn(u8) -> u8, usize);
+
+assert_instantiated!(wrapper_size::<u8>, wrapper_size::<u64>);
+assert_instantiated!(wrapper_align::<str>, option_size::<u32>, not_zero::<u8>);
+
+#[test]
+fn from_generic_code() {
+    fn new<T>(value: T) -> Wrapper<T> {
+        wrapper_size::<T>();
+        Wrapper(value, PhantomData)
+    }
+    let _ = new(1u16);
+    let _ = new([0u8; 3]);
+}
@@ -0,0 +1,42 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+#[allow(dead_code)]
+struct Packet {
+    data: *const u8,
+    len: usize,
+    id: u32,
+}
+
+assert_layout_by_target!(Packet {
+    target_pointer_width = "64" => size 24 align 8,
+    target_pointer_width = "32" => size 12 align 4,
+    _ => compile_error,
+});
+
+const WORD: usize = core::mem::size_of::<usize>();
+
+// Only the first matching arm is checked.
+assert_layout_by_target!(usize {
+    any(target_pointer_width = "16", target_pointer_width = "32",
+        target_pointer_width = "64") => size WORD,
+    target_pointer_width = "64" => size 0 align 0,
+    _ => compile_error("unsupported target"),
+});
+
+assert_layout_by_target!([u8; 3] {
+    any() => size 0,
+    _ => size 3 align 1
+});
+
+assert_layout_by_target!(u16 { _ => align (core::mem::size_of::<u16>()) });
+
+#[test]
+fn in_fn() {
+    assert_layout_by_target!(Packet {
+        not(any()) => size (3 * WORD),
+    });
+}
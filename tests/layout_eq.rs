@@ -0,0 +1,82 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u16,
+    flags: u16,
+}
+
+mod vendored {
+    #[allow(dead_code)]
+    #[repr(C)]
+    pub struct Header {
+        pub magic: u32,
+        pub size: u16,
+        pub flags: u16,
+    }
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    pub struct Packet {
+        pub header: Header,
+        pub body: [u8; 8],
+    }
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Packet {
+    header: Header,
+    body: [u8; 8],
+}
+
+#[allow(dead_code)]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    value: u32,
+}
+
+#[allow(dead_code)]
+#[repr(C, packed)]
+struct PackedPair(u8, [u8; 4]);
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Owned {
+    name: String,
+    items: Vec<u8>,
+}
+
+impl Drop for Owned {
+    fn drop(&mut self) {}
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct OwnedMirror {
+    name: String,
+    items: Vec<u8>,
+}
+
+assert_layout_eq!(Header, vendored::Header);
+assert_layout_eq!(Header, vendored::Header; magic => magic, len => size,);
+assert_layout_eq!(Packed, [u8; 5]);
+assert_layout_eq!(Packed, PackedPair; tag => 0, value => 1);
+assert_layout_eq!(Packet, vendored::Packet;
+    header.magic => header.magic,
+    header.len => header.size,
+    header.flags => header.flags,
+    body => body,
+);
+assert_layout_eq!(Owned, OwnedMirror; name => name, items => items);
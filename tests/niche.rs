@@ -0,0 +1,40 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::num::NonZeroU32;
+use core::ptr::NonNull;
+
+#[allow(dead_code)]
+struct Handle(NonZeroU32);
+
+#[allow(dead_code)]
+enum Event {
+    Click(u32, u32),
+    Key(char),
+    Quit,
+}
+
+#[allow(dead_code)]
+enum Tri {
+    A,
+    B,
+    C,
+}
+
+assert_niche!(Handle);
+assert_niche!(NonNull<u8>, &str, &mut [u8], fn(), Event, Tri,);
+
+assert_niche_count!(u32 == 0);
+assert_niche_count!(Handle == 1);
+assert_niche_count!(NonNull<u8> < 2);
+assert_niche_count!(bool == 254);
+assert_niche_count!(Option<bool> == 253);
+assert_niche_count!(Tri == 253);
+assert_niche_count!(char > 255);
+assert_niche_count!(char > 510);
+assert_niche_count!(Option<&u8> == 0);
+assert_niche_count!(Event in 1..);
+assert_niche_count!((bool, bool) >= 254);
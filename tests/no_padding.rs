@@ -0,0 +1,71 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+extern crate alloc;
+
+use alloc::string::String;
+
+const SIZE: usize = 4;
+const FIELDS_SIZE: usize = 2;
+const OFFSETS: usize = 8;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u16,
+    flags: u16,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Point(i32, i32);
+
+#[allow(dead_code)]
+struct Reordered {
+    a: u8,
+    b: u32,
+    c: u16,
+    d: u8,
+}
+
+#[allow(dead_code)]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    value: u64,
+}
+
+#[allow(dead_code)]
+struct Empty;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Counted {
+    a: [u8; SIZE],
+    b: u32,
+    c: [u16; FIELDS_SIZE],
+    d: [u8; OFFSETS],
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Named {
+    name: String,
+    id: usize,
+}
+
+impl Drop for Named {
+    fn drop(&mut self) {}
+}
+
+assert_no_padding!(Header: magic: u32, len: u16, flags: u16);
+assert_no_padding!(Point: 0: i32, 1: i32,);
+assert_no_padding!(Reordered: a: u8, b: u32, c: u16, d: u8);
+assert_no_padding!(Packed: tag: u8, value: u64);
+assert_no_padding!((u32, u32): 0: u32, 1: u32);
+assert_no_padding!(Counted: a: [u8; SIZE], b: u32, c: [u16; FIELDS_SIZE], d: [u8; OFFSETS]);
+assert_no_padding!(Named: name: String, id: usize);
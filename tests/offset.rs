@@ -0,0 +1,39 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u32,
+    flags: u16,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Packet<T> {
+    header: Header,
+    body: T,
+    pair: (u8, u16),
+}
+
+assert_offset_eq!(Header: magic => 0, len => 4, flags => 8);
+assert_offset_eq!(Header: magic => 0,);
+assert_offset_eq!(Packet<[u8; 4]>: header.magic => 0, header.flags => 8, body => 12);
+assert_offset_eq!(Packet<u64>: body => 16, pair.0 => 24, pair.1 => 26);
+
+assert_offset_lt!(Header: magic => 1, len => 8);
+assert_offset_le!(Header: magic => 0, flags => 8);
+assert_offset_gt!(Packet<u8>: body => 11, header.len => 0);
+assert_offset_ge!(Packet<u8>: pair => 13, header.flags => 8);
+
+mod m {
+    #[allow(dead_code)]
+    #[repr(C)]
+    pub struct Pair(pub u8, pub u32);
+}
+
+assert_offset_eq!(m::Pair: 0 => 0, 1 => 4);
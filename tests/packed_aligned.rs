@@ -0,0 +1,61 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+const OFFSET: usize = 2;
+const ALIGN: usize = 4;
+
+#[allow(dead_code)]
+#[repr(C, packed)]
+struct Frame {
+    kind: u8,
+    flags: u8,
+    len: u16,
+    crc: u32,
+    body: [u8; 3],
+    tail: u8,
+}
+
+#[allow(dead_code)]
+#[repr(C, packed(2))]
+struct Pair(u16, u64);
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Aligned {
+    a: u8,
+    b: u64,
+}
+
+#[allow(dead_code)]
+#[repr(C, packed(4))]
+struct Counted {
+    a: [u16; OFFSET],
+    b: [u32; ALIGN],
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Guarded {
+    id: u32,
+    flag: u8,
+}
+
+impl Drop for Guarded {
+    fn drop(&mut self) {}
+}
+
+assert_packed_aligned!(
+    Frame: kind: u8,
+    flags: u8,
+    len: u16,
+    crc: u32,
+    body: [u8; 3],
+    tail: u8,
+);
+assert_packed_aligned!(Pair: 0: u16);
+assert_packed_aligned!(Aligned: a: u8, b: u64);
+assert_packed_aligned!(Counted: a: [u16; OFFSET], b: [u32; ALIGN]);
+assert_packed_aligned!(Guarded: id: u32, flag: u8);
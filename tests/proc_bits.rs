@@ -0,0 +1,56 @@
+#![cfg(feature = "proc")]
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::marker::PhantomData;
+use static_assertions_next::{AnyBitPattern, Zeroable};
+
+#[derive(Zeroable)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    flags: [u8; 4],
+    next: *const Header,
+}
+
+#[derive(Zeroable)]
+struct Nested(Header, Option<&'static Header>);
+
+#[derive(Zeroable, AnyBitPattern)]
+#[repr(C)]
+struct Sample {
+    time: u64,
+    channels: [f32; 2],
+}
+
+#[derive(Zeroable, AnyBitPattern)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+#[derive(Zeroable, AnyBitPattern)]
+struct Buffer<'a, T, const N: usize> {
+    values: [T; N],
+    _marker: PhantomData<&'a T>,
+}
+
+#[derive(Zeroable)]
+struct Unit;
+
+mod reexport {
+    pub use static_assertions_next::{AnyBitPattern, Zeroable};
+}
+
+#[derive(Zeroable, AnyBitPattern)]
+#[bits(crate = crate::reexport)]
+struct Renamed(u8, [u16; 2]);
+
+assert_zeroable!(Header, Nested, Sample, Bits, Unit);
+assert_any_bit_pattern!(Sample, Bits, Buffer<'static, u16, 3>, Renamed);
+assert_impl_not_any!(Buffer<'static, bool, 3>: AnyBitPattern);
+assert_impl_not_any!(Buffer<'static, bool, 3>: Zeroable);
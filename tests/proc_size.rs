@@ -1,9 +1,20 @@
 #![cfg(feature = "proc")]
+#![allow(dead_code)]
 
 #[macro_use]
-extern crate static_assertions;
+extern crate static_assertions_next;
 
 #[assert(size == 4, align == 4)]
 struct Foo {
     value: i32,
 }
+
+mod by_path {
+    use static_assertions_next::assert;
+
+    #[assert(size == 8)]
+    struct Bar(u32, u32);
+
+    #[static_assertions_next::assert(align == 2)]
+    struct Baz(u16);
+}
@@ -0,0 +1,55 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::fmt::Debug;
+
+#[allow(dead_code)]
+struct Packet {
+    len: u16,
+    data: [u8],
+}
+
+#[allow(dead_code)]
+struct Named<T: ?Sized> {
+    id: u32,
+    value: T,
+}
+
+#[allow(dead_code)]
+struct Entry<K, V: ?Sized> {
+    key: K,
+    value: V,
+}
+
+assert_thin_ptr!(u8, (), [u64; 3], &[u8], *const str, Named<u32>);
+assert_thin_ptr!(fn sized_thin for(T) T, Named<T>);
+assert_thin_ptr!(fn any_thin for(T: ?Sized) T);
+assert_thin_ptr!(for(T) T, Named<T>, Option<&T>);
+assert_thin_ptr!(for('a, T: ?Sized + 'a) &'a T, *mut T);
+assert_thin_ptr!(pub fn boxed_thin for(T: ?Sized) &T, *mut T);
+
+assert_fat_ptr!([u8], str, dyn Debug, dyn Debug + Send + Sync, Packet);
+assert_fat_ptr!(Named<[u16]>, Named<dyn Debug>);
+assert_fat_ptr!(fn slice_fat for(T) [T], Named<[T]>);
+assert_fat_ptr!(for(T: Debug) [T], Named<[T]>, Named<dyn Debug>);
+
+assert_ptr_metadata!(u32 => (), &str => ());
+assert_ptr_metadata!([u8] => usize, str => usize, Packet => usize, Named<str> => usize);
+assert_ptr_metadata!(dyn Debug => vtable, dyn Debug + Send + Sync => vtable,);
+assert_ptr_metadata!(Option<u8> => (), Entry<u8, [u8]> => usize, dyn Fn(u8) -> u8 => vtable);
+
+fn store<T: ?Sized>(ptr: *const T) -> usize {
+    any_thin::<T>();
+    ptr as *const () as usize
+}
+
+#[test]
+fn generic_statement() {
+    let value = 7_u32;
+    assert_ne!(store(&value), 0);
+}
+
+assert_instantiated!(sized_thin::<u16>, boxed_thin::<[u8]>, slice_fat::<u8>);
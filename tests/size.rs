@@ -0,0 +1,43 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+#[allow(dead_code)]
+struct SmallMsg {
+    id: u32,
+    payload: [u8; 60],
+}
+
+assert_size_ne!(u8, u16, u32);
+assert_size_ne!(SmallMsg, [u8; 63],);
+
+assert_size_lt!(u8, u16, u32, u64);
+assert_size_lt!((), u8);
+assert_size_le!(SmallMsg, [u8; 64]);
+assert_size_le!(u8, i8, u16, [u8; 2], u32);
+assert_size_gt!(u64, u32, u16, u8, ());
+assert_size_ge!([u8; 64], SmallMsg, u32, i32);
+
+#[test]
+fn in_fn() {
+    assert_size_lt!(u8, usize);
+    assert_size_ge!(usize, *const u8, u8);
+}
+
+assert_size!(u8 == 1);
+assert_size!(u16 != 1);
+assert_size!(SmallMsg <= 64);
+assert_size!(Option<u32> < 16);
+assert_size!(Option<Option<u8>> > 1);
+assert_size!(Result<u8, u16> >= 2 * 2);
+assert_size!(Option<&u8> == (core::mem::size_of::<usize>()));
+assert_size!([u8; 16] in 8..=32);
+assert_size!([u8; 16] in ..17);
+assert_size!(fn() -> u8 in 1..);
+assert_size!(&[u8] in core::mem::size_of::<usize>()..=64);
+
+assert_align!(u64 >= 4);
+assert_align!(u8 == 1);
+assert_align!([u16; 3] in 2..4);
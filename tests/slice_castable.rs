@@ -0,0 +1,35 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+#[allow(dead_code)]
+#[repr(C, align(4))]
+struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Sample {
+    left: f32,
+    right: f32,
+}
+
+assert_slice_castable!([u32] => [u8], [u64] => [u16], [u128] => [u32]);
+assert_slice_castable!([Rgba] => [u8], [Rgba] => [[u8; 4]], [Sample] => [f32],);
+assert_slice_castable!([[u16; 6]] => [[u16; 3]], [()] => [()], [()] => [u8]);
+assert_slice_castable!([Rgba] <=> [u32], [f32] <=> [i32], [Sample] <=> [[f32; 2]]);
+assert_slice_castable!([u8] <=> [i8], [[u8; 3]] => [u8], [u16] <=> [u16]);
+
+const MULTIPLE: usize = 2;
+
+const fn multiple() -> usize {
+    4
+}
+
+assert_slice_castable!([u32] => [[u16; MULTIPLE]], [[u8; multiple()]] => [u8]);
@@ -58,6 +58,7 @@ assert_impl!(Test: !(Copy) & !(!Clone));
 assert_impl!(Test: !(Copy & Clone));
 assert_impl!(str: !Copy & !Clone);
 
+#[allow(dead_code)]
 #[derive(Clone)]
 struct Box<T>(T);
 
@@ -0,0 +1,26 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::marker::PhantomData;
+use core::num::NonZeroU64;
+
+#[repr(transparent)]
+struct Meters(f64);
+
+#[repr(transparent)]
+struct UserId(NonZeroU64);
+
+#[allow(dead_code)]
+#[repr(transparent)]
+struct Marked<T>(u16, PhantomData<T>);
+
+assert_transparent!(Meters => f64);
+assert_transparent!(
+    UserId => NonZeroU64,
+    UserId => u64,
+    Marked<u8> => u16,
+    [u8; 4] => (u8, u8, u8, u8),
+);
@@ -0,0 +1,54 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+#[allow(dead_code)]
+enum Event {
+    Click(u32, u32),
+    Key { code: u32, shift: bool },
+    Scroll(f32),
+    Quit,
+}
+
+#[allow(dead_code)]
+enum Borrowed<'a, T> {
+    Slice(&'a [T]),
+    Value { value: T },
+}
+
+#[allow(dead_code)]
+enum Enum {
+    Unit,
+    Boxed(Option<&'static Enum>),
+    Named { inner: Option<&'static Enum> },
+}
+
+#[allow(dead_code)]
+enum Guarded {
+    Idle,
+    Busy(u32),
+}
+
+impl Drop for Guarded {
+    fn drop(&mut self) {}
+}
+
+assert_variant_size_le!(Event:
+    Click(u32, u32),
+    Key { code: u32, shift: bool },
+    Scroll(f32),
+    Quit;
+    8
+);
+assert_variant_size_le!(Event: Quit, Scroll(f32,), Key { shift: bool, code: u32, }, Click(u32, u32,); 8,);
+assert_variant_size_le!(Borrowed<'static, u8>: Slice(&'static [u8]), Value { value: u8 }; 16);
+assert_variant_size_le!(Option<u64>: None, Some(u64); 8);
+assert_variant_size_le!(Enum:
+    Unit,
+    Boxed(Option<&'static Enum>),
+    Named { inner: Option<&'static Enum> };
+    8
+);
+assert_variant_size_le!(Guarded: Idle, Busy(u32); 4);
@@ -0,0 +1,34 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::num::{NonZeroU32, NonZeroUsize, Wrapping};
+use core::ptr::NonNull;
+
+assert_zeroable!(u8, i128, usize, f64, ());
+assert_zeroable!([u32; 8], [[i16; 2]; 3], PhantomData<&'static str>);
+assert_zeroable!(
+    *const u8,
+    *mut u64,
+    Option<&'static u8>,
+    Option<&'static mut u8>
+);
+assert_zeroable!(Option<NonNull<u8>>, Option<NonZeroU32>, Wrapping<u16>,);
+assert_zeroable!(ManuallyDrop<[u8; 4]>, Cell<f32>, MaybeUninit<&'static u8>);
+
+assert_any_bit_pattern!(u8, i128, usize, f32, (), [u64; 4], Wrapping<i8>);
+assert_any_bit_pattern!(Option<NonZeroUsize>, MaybeUninit<bool>);
+assert_any_bit_pattern!(PhantomData<bool>, ManuallyDrop<f64>, Cell<u32>);
+
+assert_impl_not_any!(bool: static_assertions_next::Zeroable);
+assert_impl_not_any!(char: static_assertions_next::Zeroable);
+assert_impl_not_any!([bool; 4]: static_assertions_next::Zeroable);
+assert_impl_not_any!(&'static u8: static_assertions_next::Zeroable);
+assert_impl_not_any!(NonZeroU32: static_assertions_next::Zeroable);
+assert_impl_not_any!(NonNull<u8>: static_assertions_next::Zeroable);
+assert_impl_not_any!(*const u8: static_assertions_next::AnyBitPattern);
@@ -0,0 +1,45 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions_next;
+
+use core::marker::PhantomData;
+
+struct Token;
+
+#[allow(dead_code)]
+struct Marker<T: ?Sized>(PhantomData<T>);
+
+#[allow(dead_code)]
+struct Handle(u32);
+
+assert_zst!(Token);
+assert_zst!((), [u64; 0], Marker<str>, PhantomData<Handle>,);
+assert_zst!(pub fn marker_zst for(T: ?Sized) Marker<T>, PhantomData<T>);
+
+assert_not_zst!(Handle, Option<Token>, &Token, fn());
+assert_not_zst!(fn array_not_zst for(T, const N: usize) [T; N], Option<T>);
+
+assert_instantiated!(marker_zst::<str>, array_not_zst::<u8, 4>);
+
+// Checked for every `T` at the item level.
+assert_zst!(for(T: ?Sized) Marker<T>, PhantomData<T>);
+assert_zst!(for('a, T: Copy + 'a, const N: usize) Marker<&'a [T; N]>);
+assert_not_zst!(for(T) Option<T>, (T, u8));
+
+assert_zst!(fn generic_zst for(T) T);
+
+impl<T> Marker<T> {
+    fn new() -> Self {
+        assert_zst!(for(T) Marker<T>);
+        generic_zst::<Self>();
+        Marker(PhantomData)
+    }
+}
+
+#[test]
+fn in_generic_fn() {
+    let _ = Marker::<Handle>::new();
+    let _ = Token;
+}